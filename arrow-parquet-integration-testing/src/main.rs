@@ -173,6 +173,8 @@ fn main() -> Result<()> {
         compression: args.compression.into(),
         version: args.version.into(),
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let encodings = schema
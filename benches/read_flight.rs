@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
+use arrow2::io::flight::*;
+use arrow2::io::ipc::read::Dictionaries;
+
+fn add_benchmark(c: &mut Criterion) {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("c1", data_type, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header).unwrap();
+
+    // two alternating dictionaries, so that every upsert replaces the previous one
+    let messages = [["a", "b"], ["c", "d"]]
+        .iter()
+        .map(|values| {
+            let values = Utf8Array::<i32>::from_slice(values).boxed();
+            let array =
+                DictionaryArray::try_from_keys(PrimitiveArray::from_vec(vec![0, 1]), values)
+                    .unwrap();
+            let chunk = Chunk::new(vec![array.boxed()]);
+            let (mut dicts, _) = serialize_batch(&chunk, &fields, &Default::default()).unwrap();
+            dicts.pop().unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    c.bench_function("flight dictionary upsert 10k", |b| {
+        b.iter(|| {
+            let mut dictionaries = Dictionaries::with_capacity(1);
+            for message in messages.iter().cycle().take(10_000) {
+                deserialize_dictionary(message, &schema.fields, &ipc_schema, &mut dictionaries)
+                    .unwrap();
+            }
+        })
+    });
+
+    c.bench_function("flight stream reader dictionary upsert 10k", |b| {
+        b.iter(|| {
+            let mut reader = FlightStreamReader::with_schema(schema.clone(), ipc_schema.clone());
+            for message in messages.iter().cycle().take(10_000) {
+                reader.decode_message(message).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -18,6 +18,8 @@ fn write(array: &dyn Array, encoding: Encoding) -> Result<()> {
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let row_groups = RowGroupIterator::try_new(
@@ -17,6 +17,8 @@ fn write_chunk(path: &str, schema: Schema, chunk: Chunk<Box<dyn Array>>) -> Resu
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let iter = vec![Ok(chunk)];
@@ -18,6 +18,8 @@ async fn write_batch(path: &str, schema: Schema, columns: Chunk<Box<dyn Array>>)
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let mut stream = futures::stream::iter(vec![Ok(columns)].into_iter());
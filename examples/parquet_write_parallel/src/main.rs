@@ -48,6 +48,8 @@ fn parallel_write(path: &str, schema: Schema, chunks: &[Chunk]) -> Result<()> {
         compression: CompressionOptions::Snappy,
         version: Version::V2,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let encoding_map = |data_type: &DataType| {
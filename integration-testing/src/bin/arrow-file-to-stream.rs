@@ -34,7 +34,7 @@ fn main() -> Result<()> {
     let metadata = read::read_file_metadata(&mut f)?;
     let mut reader = read::FileReader::new(f, metadata.clone(), None, None);
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::new(None);
     let mut writer = write::StreamWriter::new(std::io::stdout(), options);
 
     let fields = metadata.ipc_schema.fields.clone();
@@ -56,7 +56,7 @@ fn json_to_arrow(json_name: &str, arrow_name: &str, verbose: bool) -> Result<()>
     let json_file = read_json_file(json_name)?;
 
     let arrow_file = File::create(arrow_name)?;
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::new(None);
     let mut writer = write::FileWriter::try_new(
         arrow_file,
         &json_file.schema,
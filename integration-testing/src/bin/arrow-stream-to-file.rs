@@ -28,7 +28,7 @@ fn main() -> Result<()> {
 
     let writer = io::stdout();
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::new(None);
     let mut writer = write::FileWriter::try_new(
         writer,
         &metadata.schema,
@@ -104,7 +104,7 @@ fn new_stream(
     descriptor: FlightDescriptor,
     chunks: Vec<ChunkBox>,
 ) -> BoxStream<'static, FlightData> {
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::new(None);
 
     let mut schema = flight::serialize_schema(schema, Some(&fields));
     schema.flight_descriptor = Some(descriptor);
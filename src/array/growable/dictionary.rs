@@ -15,7 +15,8 @@ use super::{
 /// Concrete [`Growable`] for the [`DictionaryArray`].
 /// # Implementation
 /// This growable does not perform collision checks and instead concatenates
-/// the values of each [`DictionaryArray`] one after the other.
+/// the values of each [`DictionaryArray`] one after the other, except when every
+/// [`DictionaryArray`] has the same single value, in which case that value is kept once.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     data_type: DataType,
     keys_values: Vec<&'a [K]>,
@@ -31,6 +32,13 @@ fn concatenate_values<K: DictionaryKey>(
     arrays_values: &[&dyn Array],
     capacity: usize,
 ) -> (Box<dyn Array>, Vec<usize>) {
+    // dictionaries of a single, common value (e.g. constant columns) stay of a single value
+    if let Some(first) = arrays_values.first() {
+        if first.len() == 1 && arrays_values.iter().all(|values| values == first) {
+            return (first.to_boxed(), vec![0; arrays_keys.len() + 1]);
+        }
+    }
+
     let mut mutable = make_growable(arrays_values, false, capacity);
     let mut offsets = Vec::with_capacity(arrays_keys.len() + 1);
     offsets.push(0);
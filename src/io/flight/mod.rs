@@ -1,5 +1,10 @@
 //! Serialization and deserialization to Arrow's flight protocol
 
+use std::io::Write;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+
 use arrow_format::flight::data::{FlightData, SchemaResult};
 use arrow_format::ipc;
 use arrow_format::ipc::planus::ReadAsRoot;
@@ -12,40 +17,210 @@ use crate::{
     io::ipc::read,
     io::ipc::write,
     io::ipc::write::common::{encode_chunk, DictionaryTracker, EncodedData},
+    io::ipc::write::writer::State,
+    io::ipc::write::Compression,
 };
 
 use super::ipc::read::Dictionaries;
-use super::ipc::{IpcField, IpcSchema};
+use super::ipc::{IpcField, IpcSchema, CONTINUATION_MARKER};
 
 pub use super::ipc::write::default_ipc_fields;
-pub use crate::io::ipc::write::common::WriteOptions;
+pub use crate::io::ipc::write::common::{DictionaryPolicy, WriteOptions};
 
 /// Serializes [`Chunk`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
+///
+/// All dictionaries of `chunk` are serialized in full, since no dictionaries are tracked
+/// across calls; [`WriteOptions::dictionary_policy`] is therefore irrelevant here.
+/// Use [`FlightSerializer`] to only serialize the dictionaries that changed.
 /// # Errors
 /// This function errors iff `fields` is not consistent with `columns`
 pub fn serialize_batch(
     chunk: &Chunk<Box<dyn Array>>,
     fields: &[IpcField],
     options: &WriteOptions,
+) -> Result<(Vec<FlightData>, FlightData)> {
+    FlightSerializer {
+        dictionary_tracker: DictionaryTracker::with_capacity(count_dictionaries(fields)),
+        options: *options,
+    }
+    .serialize_batch(chunk, fields)
+}
+
+/// Serializes [`Chunk`] like [`serialize_batch`], also returning the size in bytes of
+/// each [`FlightData`], i.e. the length of its header plus the length of its body.
+/// # Errors
+/// This function errors iff `fields` is not consistent with `columns`
+pub fn serialize_batch_with_sizes(
+    chunk: &Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+) -> Result<(Vec<(FlightData, usize)>, (FlightData, usize))> {
+    let (dictionaries, batch) = serialize_batch(chunk, fields, options)?;
+    let dictionaries = dictionaries
+        .into_iter()
+        .map(|data| {
+            let size = data_size(&data);
+            (data, size)
+        })
+        .collect();
+    let size = data_size(&batch);
+    Ok((dictionaries, (batch, size)))
+}
+
+fn data_size(data: &FlightData) -> usize {
+    data.data_header.len() + data.data_body.len()
+}
+
+/// A stateful serializer of a stream of [`Chunk`]s to [`FlightData`] that tracks the
+/// dictionaries already serialized, so that a dictionary is only serialized when it changed.
+///
+/// A changed dictionary is serialized according to [`WriteOptions::dictionary_policy`]:
+/// in full, as a delta or by erroring.
+pub struct FlightSerializer {
+    dictionary_tracker: DictionaryTracker,
+    options: WriteOptions,
+}
+
+impl FlightSerializer {
+    /// Creates a new [`FlightSerializer`] that has not serialized any dictionary.
+    pub fn new(options: WriteOptions) -> Self {
+        Self {
+            dictionary_tracker: DictionaryTracker::with_capacity(0),
+            options,
+        }
+    }
+
+    /// Serializes [`Chunk`] to a vector of [`FlightData`] representing the dictionaries that
+    /// changed since the previous call and a [`FlightData`] representing the batch.
+    /// # Errors
+    /// This function errors iff `fields` is not consistent with `columns` or a dictionary
+    /// changed and [`WriteOptions::dictionary_policy`] is [`DictionaryPolicy::Error`].
+    pub fn serialize_batch(
+        &mut self,
+        chunk: &Chunk<Box<dyn Array>>,
+        fields: &[IpcField],
+    ) -> Result<(Vec<FlightData>, FlightData)> {
+        if fields.len() != chunk.arrays().len() {
+            return Err(Error::InvalidArgumentError("The argument `fields` must be consistent with the columns' schema. Use e.g. &arrow2::io::flight::default_ipc_fields(&schema.fields)".to_string()));
+        }
+
+        let (encoded_dictionaries, encoded_batch) =
+            encode_chunk(chunk, fields, &mut self.dictionary_tracker, &self.options)?;
+
+        let flight_dictionaries = encoded_dictionaries.into_iter().map(Into::into).collect();
+        let flight_batch = encoded_batch.into();
+
+        Ok((flight_dictionaries, flight_batch))
+    }
+}
+
+fn count_dictionaries(fields: &[IpcField]) -> usize {
+    fields
+        .iter()
+        .map(|field| field.dictionary_id.is_some() as usize + count_dictionaries(&field.fields))
+        .sum()
+}
+
+/// Serializes the columns of [`Chunk`] selected by `projection` to a vector of [`FlightData`]
+/// representing the serialized dictionaries and a [`FlightData`] representing the batch.
+///
+/// The batch contains the selected columns in the order of `projection` and must be read
+/// with a schema whose fields are the correspondingly projected fields.
+/// # Errors
+/// This function errors iff `fields` is not consistent with `columns` or
+/// `projection` contains an index out of bounds of `fields`.
+pub fn serialize_batch_projected(
+    chunk: &Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+    projection: &[usize],
 ) -> Result<(Vec<FlightData>, FlightData)> {
     if fields.len() != chunk.arrays().len() {
         return Err(Error::InvalidArgumentError("The argument `fields` must be consistent with the columns' schema. Use e.g. &arrow2::io::flight::default_ipc_fields(&schema.fields)".to_string()));
     }
+    if let Some(index) = projection.iter().find(|&&index| index >= fields.len()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The projection index {index} is out of bounds for {} fields",
+            fields.len()
+        )));
+    }
+
+    let arrays = projection
+        .iter()
+        .map(|&index| chunk.arrays()[index].clone())
+        .collect();
+    let fields = projection
+        .iter()
+        .map(|&index| fields[index].clone())
+        .collect::<Vec<_>>();
+
+    serialize_batch(&Chunk::new(arrays), &fields, options)
+}
 
-    let mut dictionary_tracker = DictionaryTracker {
-        dictionaries: Default::default(),
-        cannot_replace: false,
+/// Serializes a [`Schema`] and the first [`Chunk`] of a stream to the [`FlightData`] that
+/// starts the stream (e.g. of a `DoGet` response): the schema message, followed by the
+/// dictionary messages and by the record batch message.
+///
+/// When `ipc_fields` is `None`, [`default_ipc_fields`] of `schema` is used.
+/// # Errors
+/// This function errors iff `ipc_fields` is not consistent with the columns of `chunk`
+pub fn serialize_schema_and_batch(
+    schema: &Schema,
+    ipc_fields: Option<&[IpcField]>,
+    chunk: &Chunk<Box<dyn Array>>,
+    options: &WriteOptions,
+) -> Result<Vec<FlightData>> {
+    let default_fields;
+    let ipc_fields = match ipc_fields {
+        Some(ipc_fields) => ipc_fields,
+        None => {
+            default_fields = default_ipc_fields(&schema.fields);
+            &default_fields
+        }
     };
 
-    let (encoded_dictionaries, encoded_batch) =
-        encode_chunk(chunk, fields, &mut dictionary_tracker, options)
-            .expect("DictionaryTracker configured above to not error on replacement");
+    let (dictionaries, batch) = serialize_batch(chunk, ipc_fields, options)?;
 
-    let flight_dictionaries = encoded_dictionaries.into_iter().map(Into::into).collect();
-    let flight_batch = encoded_batch.into();
+    let mut datas = Vec::with_capacity(dictionaries.len() + 2);
+    datas.push(serialize_schema(schema, Some(ipc_fields)));
+    datas.extend(dictionaries);
+    datas.push(batch);
+    Ok(datas)
+}
 
-    Ok((flight_dictionaries, flight_batch))
+/// Validates that `chunk` is consistent with `schema`, i.e. that it has one column per field
+/// and that the [`DataType`] of each column equals the one of its field.
+///
+/// [`serialize_batch`] does not check this; call it beforehand so that a batch inconsistent
+/// with the schema advertised to the peer is caught before it is sent.
+/// # Errors
+/// This function errors iff `chunk` is not consistent with `schema`.
+pub fn validate_chunk_against_schema(chunk: &Chunk<Box<dyn Array>>, schema: &Schema) -> Result<()> {
+    if chunk.arrays().len() != schema.fields.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The chunk has {} columns but the schema has {} fields",
+            chunk.arrays().len(),
+            schema.fields.len()
+        )));
+    }
+    chunk
+        .arrays()
+        .iter()
+        .zip(schema.fields.iter())
+        .enumerate()
+        .try_for_each(|(index, (array, field))| {
+            if array.data_type() != field.data_type() {
+                Err(Error::InvalidArgumentError(format!(
+                    "The column {index} (\"{}\") has data type {:?} but its field declares {:?}",
+                    field.name,
+                    array.data_type(),
+                    field.data_type()
+                )))
+            } else {
+                Ok(())
+            }
+        })
 }
 
 impl From<EncodedData> for FlightData {
@@ -68,6 +243,22 @@ pub fn serialize_schema_to_result(
     }
 }
 
+/// Serializes a [`Schema`] and its [`IpcSchema`], e.g. as returned by [`deserialize_schemas`],
+/// to [`SchemaResult`].
+///
+/// The [`IpcField`]s (e.g. dictionary ids) of `ipc_schema` are preserved; the endianness
+/// is the one of this platform, as for any other serialized schema.
+pub fn serialize_ipc_schema_to_result(schema: &Schema, ipc_schema: &IpcSchema) -> SchemaResult {
+    serialize_schema_to_result(schema, Some(&ipc_schema.fields))
+}
+
+/// Deserializes a [`SchemaResult`], e.g. of a `GetSchema` response, into [`Schema`], [`IpcSchema`].
+/// # Errors
+/// Errors are tagged with [`FlightStage::Schema`] (see [`stage_of`]).
+pub fn deserialize_schema_result(result: &SchemaResult) -> Result<(Schema, IpcSchema)> {
+    deserialize_schemas(&result.schema)
+}
+
 /// Serializes a [`Schema`] to [`FlightData`].
 pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> FlightData {
     FlightData {
@@ -76,6 +267,41 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Fli
     }
 }
 
+/// Serializes a [`Schema`] to both the [`SchemaResult`] of a `GetSchema` response and the
+/// [`FlightData`] of the first message of a `DoGet` stream, so that both are derived
+/// from the same `schema` and `ipc_fields` (e.g. the same dictionary ids).
+pub fn serialize_schema_for_get_schema_and_do_get(
+    schema: &Schema,
+    ipc_fields: Option<&[IpcField]>,
+) -> (SchemaResult, FlightData) {
+    let bytes = _serialize_schema(schema, ipc_fields);
+    (
+        SchemaResult {
+            schema: bytes.clone(),
+        },
+        FlightData {
+            data_header: bytes,
+            ..Default::default()
+        },
+    )
+}
+
+/// Checks that the schema of a `GetSchema` response ([`SchemaResult::schema`]) and the one of
+/// a `DoGet` stream ([`FlightData::data_header`] of its first message) are equal, including
+/// their [`IpcSchema`]s. Useful in tests of flight servers.
+/// # Errors
+/// This function errors iff any of the schemas fails to deserialize or the schemas are different.
+pub fn check_schema_consistent(get_schema: &[u8], do_get_schema: &[u8]) -> Result<()> {
+    let get_schema = deserialize_schemas(get_schema)?;
+    let do_get_schema = deserialize_schemas(do_get_schema)?;
+    if get_schema != do_get_schema {
+        return Err(Error::InvalidArgumentError(format!(
+            "The schema of GetSchema ({get_schema:?}) differs from the schema of DoGet ({do_get_schema:?})"
+        )));
+    }
+    Ok(())
+}
+
 /// Convert a [`Schema`] to bytes in the format expected in [`arrow_format::flight::data::FlightInfo`].
 pub fn serialize_schema_to_info(
     schema: &Schema,
@@ -109,10 +335,142 @@ fn schema_as_encoded_data(schema: &Schema, ipc_fields: &[IpcField]) -> EncodedDa
     }
 }
 
+/// Returns the [`FlightData`] signaling the end of a stream: the IPC end-of-stream marker
+/// (a continuation marker followed by a zero message length) without a body.
+pub fn end_of_stream() -> FlightData {
+    let mut data_header = CONTINUATION_MARKER.to_vec();
+    data_header.extend_from_slice(&0i32.to_le_bytes());
+    FlightData {
+        data_header,
+        ..Default::default()
+    }
+}
+
+/// Returns whether `data` signals the end of a stream, i.e. whether it was produced by
+/// [`end_of_stream`] or its header is the legacy (4 zero bytes) end-of-stream marker.
+///
+/// A message carrying a body, `app_metadata` or a `flight_descriptor` is never the end of a stream.
+pub fn is_end_of_stream(data: &FlightData) -> bool {
+    if !data.data_body.is_empty()
+        || !data.app_metadata.is_empty()
+        || data.flight_descriptor.is_some()
+    {
+        return false;
+    }
+    match data.data_header.as_slice() {
+        [0, 0, 0, 0] => true,
+        [a, b, c, d, 0, 0, 0, 0] => [*a, *b, *c, *d] == CONTINUATION_MARKER,
+        _ => false,
+    }
+}
+
+/// The kind of message whose deserialization failed, as tagged by [`FlightError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlightStage {
+    /// The header of a message, before its kind is known
+    Header,
+    /// A schema message
+    Schema,
+    /// A dictionary message
+    Dictionary,
+    /// A record batch message
+    Batch,
+    /// A tensor or sparse tensor message
+    Tensor,
+}
+
+/// An error of deserializing a [`FlightData`], tagged with the kind of message that failed.
+///
+/// The `deserialize_*` functions of this module return it wrapped in [`Error::External`];
+/// use [`stage_of`] to recover the stage, e.g. to map failures to different gRPC status codes.
+#[derive(Debug)]
+pub struct FlightError {
+    /// The kind of message that failed to deserialize
+    pub stage: FlightStage,
+    /// The underlying error
+    pub source: Error,
+}
+
+impl std::fmt::Display for FlightError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} message failed to deserialize: {}",
+            self.stage, self.source
+        )
+    }
+}
+
+impl std::error::Error for FlightError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Returns the [`FlightStage`] that `error` was tagged with, if it was returned
+/// by the deserialization of a [`FlightData`].
+pub fn stage_of(error: &Error) -> Option<FlightStage> {
+    match error {
+        Error::External(_, error) => error.downcast_ref::<FlightError>().map(|error| error.stage),
+        _ => None,
+    }
+}
+
+fn tag(stage: FlightStage) -> impl Fn(Error) -> Error {
+    move |error| {
+        if stage_of(&error).is_some() {
+            error
+        } else {
+            Error::from_external_error(FlightError {
+                stage,
+                source: error,
+            })
+        }
+    }
+}
+
 /// Deserialize an IPC message into [`Schema`], [`IpcSchema`].
 /// Use to deserialize [`FlightData::data_header`] and [`SchemaResult::schema`].
+/// # Errors
+/// Errors are tagged with [`FlightStage::Schema`] (see [`stage_of`]).
 pub fn deserialize_schemas(bytes: &[u8]) -> Result<(Schema, IpcSchema)> {
-    read::deserialize_schema(bytes)
+    read::deserialize_schema(bytes).map_err(tag(FlightStage::Schema))
+}
+
+fn count_nodes(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => 1 + count_nodes(field.data_type()),
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            1 + fields
+                .iter()
+                .map(|field| count_nodes(field.data_type()))
+                .sum::<usize>()
+        }
+        _ => 1,
+    }
+}
+
+/// Validates that the number of field nodes of `batch` is the one declared by `fields`, so that
+/// a message inconsistent with `fields` errors before any buffer is read.
+fn check_nodes(batch: ipc::RecordBatchRef, fields: &[Field]) -> Result<()> {
+    let nodes = batch
+        .nodes()?
+        .ok_or_else(|| Error::oos("A record batch message must contain field nodes"))?
+        .len();
+    let expected = fields
+        .iter()
+        .map(|field| count_nodes(field.data_type()))
+        .sum::<usize>();
+    if nodes != expected {
+        return Err(Error::OutOfSpec(format!(
+            "The record batch message has {nodes} field nodes but the {} fields declare {expected}",
+            fields.len()
+        )));
+    }
+    Ok(())
 }
 
 /// Deserializes [`FlightData`] representing a record batch message to [`Chunk`].
@@ -122,6 +480,69 @@ pub fn deserialize_batch(
     ipc_schema: &IpcSchema,
     dictionaries: &read::Dictionaries,
 ) -> Result<Chunk<Box<dyn Array>>> {
+    deserialize_batch_with_info(data, fields, ipc_schema, dictionaries).map(|(chunk, _)| chunk)
+}
+
+/// Deserializes [`FlightData`] representing a record batch message to [`Chunk`], also
+/// returning the codec its body was compressed with, if any.
+/// # Errors
+/// Errors are tagged with [`FlightStage::Batch`] (see [`stage_of`]).
+pub fn deserialize_batch_with_info(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+) -> Result<(Chunk<Box<dyn Array>>, Option<Compression>)> {
+    _deserialize_batch_with_info(data, fields, ipc_schema, dictionaries, None)
+        .map_err(tag(FlightStage::Batch))
+}
+
+/// Deserializes [`FlightData`] representing a record batch message to a [`Chunk`] with
+/// the columns selected by `projection`, in the order of `projection`.
+///
+/// `fields` and `ipc_schema` are the ones of all the columns of the message; the columns
+/// not selected are skipped without being deserialized.
+/// # Errors
+/// This function errors iff `projection` contains duplicates or an index out of bounds of
+/// `fields`, or the message fails to deserialize. Errors of the latter are tagged with
+/// [`FlightStage::Batch`] (see [`stage_of`]).
+pub fn deserialize_batch_projected(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+    projection: &[usize],
+) -> Result<Chunk<Box<dyn Array>>> {
+    if let Some(index) = projection.iter().find(|&&index| index >= fields.len()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The projection index {index} is out of bounds for {} fields",
+            fields.len()
+        )));
+    }
+    let mut sorted = projection.to_vec();
+    sorted.sort_unstable();
+    if sorted.windows(2).any(|x| x[0] == x[1]) {
+        return Err(Error::InvalidArgumentError(
+            "The projection must not contain duplicates".to_string(),
+        ));
+    }
+    if projection.is_empty() {
+        return Ok(Chunk::new(vec![]));
+    }
+
+    let (projection, map, _) = read::prepare_projection(fields, projection.to_vec());
+    _deserialize_batch_with_info(data, fields, ipc_schema, dictionaries, Some(&projection))
+        .map(|(chunk, _)| read::apply_projection(chunk, &map))
+        .map_err(tag(FlightStage::Batch))
+}
+
+fn _deserialize_batch_with_info(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+    projection: Option<&[usize]>,
+) -> Result<(Chunk<Box<dyn Array>>, Option<Compression>)> {
     // check that the data_header is a record batch message
     let message = arrow_format::ipc::MessageRef::read_as_root(&data.data_header)
         .map_err(|err| Error::OutOfSpec(format!("Unable to get root as message: {err:?}")))?;
@@ -132,31 +553,153 @@ pub fn deserialize_batch(
     match message.header()?.ok_or_else(|| {
         Error::oos("Unable to convert flight data header to a record batch".to_string())
     })? {
-        ipc::MessageHeaderRef::RecordBatch(batch) => read::read_record_batch(
+        ipc::MessageHeaderRef::RecordBatch(batch) => {
+            check_nodes(batch, fields)?;
+            let compression = match batch.compression()? {
+                Some(compression) => Some(match compression.codec()? {
+                    ipc::CompressionType::Lz4Frame => Compression::LZ4,
+                    ipc::CompressionType::Zstd => Compression::ZSTD,
+                }),
+                None => None,
+            };
+            let chunk = read::read_record_batch(
+                batch,
+                fields,
+                ipc_schema,
+                projection,
+                None,
+                dictionaries,
+                message.version()?,
+                &mut reader,
+                0,
+                length as u64,
+                &mut Default::default(),
+            )?;
+            Ok((chunk, compression))
+        }
+        _ => Err(Error::nyi(
+            "flight currently only supports reading RecordBatch messages",
+        )),
+    }
+}
+
+/// Deserializes [`FlightData`] representing a record batch message to [`Chunk`], decoding
+/// each column independently of the others.
+///
+/// Contrarily to [`deserialize_batch`], a column that fails to deserialize does not discard
+/// the remaining columns: the returned [`Chunk`] contains the columns that were successfully
+/// deserialized (in the order of `fields`), and the returned vector contains the index
+/// and error of every column that was not. This is mostly useful to diagnose malformed messages.
+///
+/// A column whose length differs from the one declared by the message is skipped and reported
+/// like a column that failed to deserialize. A number of field nodes inconsistent with `fields`
+/// is reported with the index `fields.len()`; the columns are still deserialized in order.
+/// # Errors
+/// This function errors iff `data` is not a record batch message. All errors, including
+/// the ones of the columns, are tagged with [`FlightStage::Batch`] (see [`stage_of`]).
+pub fn deserialize_batch_lenient(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+) -> Result<(Chunk<Box<dyn Array>>, Vec<(usize, Error)>)> {
+    _deserialize_batch_lenient(data, fields, ipc_schema, dictionaries)
+        .map(|(chunk, errors)| {
+            let errors = errors
+                .into_iter()
+                .map(|(index, error)| (index, tag(FlightStage::Batch)(error)))
+                .collect();
+            (chunk, errors)
+        })
+        .map_err(tag(FlightStage::Batch))
+}
+
+fn _deserialize_batch_lenient(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+) -> Result<(Chunk<Box<dyn Array>>, Vec<(usize, Error)>)> {
+    let message = arrow_format::ipc::MessageRef::read_as_root(&data.data_header)
+        .map_err(|err| Error::OutOfSpec(format!("Unable to get root as message: {err:?}")))?;
+
+    let batch = match message.header()?.ok_or_else(|| {
+        Error::oos("Unable to convert flight data header to a record batch".to_string())
+    })? {
+        ipc::MessageHeaderRef::RecordBatch(batch) => batch,
+        _ => {
+            return Err(Error::nyi(
+                "flight currently only supports reading RecordBatch messages",
+            ))
+        }
+    };
+    let version = message.version()?;
+    let num_rows: usize = batch
+        .length()?
+        .try_into()
+        .map_err(|_| Error::oos("The record batch message has a negative length"))?;
+
+    let length = data.data_body.len();
+    let mut reader = std::io::Cursor::new(&data.data_body);
+    let mut scratch = Default::default();
+
+    let mut arrays = Vec::with_capacity(fields.len());
+    let mut errors = vec![];
+    for index in 0..fields.len() {
+        let column = read::read_record_batch(
             batch,
             fields,
             ipc_schema,
-            None,
+            Some(&[index]),
             None,
             dictionaries,
-            message.version()?,
+            version,
             &mut reader,
             0,
             length as u64,
-            &mut Default::default(),
-        ),
-        _ => Err(Error::nyi(
-            "flight currently only supports reading RecordBatch messages",
-        )),
+            &mut scratch,
+        );
+        match column {
+            Ok(chunk) if chunk.len() != num_rows => errors.push((
+                index,
+                Error::OutOfSpec(format!(
+                    "The column has {} rows but the record batch message declares {num_rows}",
+                    chunk.len()
+                )),
+            )),
+            Ok(chunk) => arrays.extend(chunk.into_arrays()),
+            Err(error) => errors.push((index, error)),
+        }
+    }
+    if let Err(error) = check_nodes(batch, fields) {
+        errors.push((fields.len(), error));
     }
+
+    Ok((Chunk::try_new(arrays)?, errors))
 }
 
 /// Deserializes [`FlightData`], assuming it to be a dictionary message, into `dictionaries`.
+///
+/// A dictionary whose id is already in `dictionaries` replaces the existing one, or is appended
+/// to it when the message is a delta dictionary batch. Its values are always deserialized into
+/// new buffers; the buffers of the replaced dictionary are not reused.
+/// # Errors
+/// Errors are tagged with [`FlightStage::Dictionary`] (see [`stage_of`]).
 pub fn deserialize_dictionary(
     data: &FlightData,
     fields: &[Field],
     ipc_schema: &IpcSchema,
     dictionaries: &mut read::Dictionaries,
+) -> Result<()> {
+    _deserialize_dictionary(data, fields, ipc_schema, dictionaries)
+        .map_err(tag(FlightStage::Dictionary))
+}
+
+fn _deserialize_dictionary(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
 ) -> Result<()> {
     let message = ipc::MessageRef::read_as_root(&data.data_header)?;
 
@@ -185,27 +728,185 @@ pub fn deserialize_dictionary(
     Ok(())
 }
 
+/// Deserializes a sequence of [`FlightData`] dictionary messages, such as the ones sent before
+/// the first record batch of a stream, into a new [`Dictionaries`].
+/// # Errors
+/// This function errors iff
+/// * any of `datas` is not a dictionary message
+/// * two messages declare the same dictionary id
+/// * any of the dictionaries fails to deserialize
+///
+/// Errors are tagged with [`FlightStage::Dictionary`] (see [`stage_of`]).
+pub fn build_dictionaries(
+    datas: &[FlightData],
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+) -> Result<Dictionaries> {
+    _build_dictionaries(datas, fields, ipc_schema).map_err(tag(FlightStage::Dictionary))
+}
+
+fn _build_dictionaries(
+    datas: &[FlightData],
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+) -> Result<Dictionaries> {
+    let mut dictionaries = Dictionaries::with_capacity(datas.len());
+    for data in datas {
+        let message = ipc::MessageRef::read_as_root(&data.data_header)?;
+
+        let batch = if let ipc::MessageHeaderRef::DictionaryBatch(batch) = message
+            .header()?
+            .ok_or_else(|| Error::oos("Header is required"))?
+        {
+            batch
+        } else {
+            return Err(Error::oos(
+                "Dictionaries can only be built from dictionary messages",
+            ));
+        };
+
+        let id = batch.id()?;
+        if dictionaries.contains_key(&id) {
+            return Err(Error::oos(format!(
+                "The dictionary with id {id} was sent more than once"
+            )));
+        }
+
+        let length = data.data_body.len();
+        let mut reader = std::io::Cursor::new(&data.data_body);
+        read::read_dictionary(
+            batch,
+            fields,
+            ipc_schema,
+            &mut dictionaries,
+            &mut reader,
+            0,
+            length as u64,
+            &mut Default::default(),
+        )?;
+    }
+    Ok(dictionaries)
+}
+
+/// Merges `maps` into a single [`Dictionaries`], e.g. to combine the dictionaries
+/// deserialized by different workers before deserializing a batch.
+/// # Errors
+/// This function errors iff two maps contain the same dictionary id with different values.
+pub fn merge_dictionaries(maps: Vec<Dictionaries>) -> Result<Dictionaries> {
+    let mut maps = maps.into_iter();
+    let mut merged = maps.next().unwrap_or_default();
+    for map in maps {
+        for (id, values) in map {
+            match merged.get(&id) {
+                Some(existing) if existing != &values => {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "The dictionary with id {id} has different values in different maps"
+                    )))
+                }
+                Some(_) => {}
+                None => {
+                    merged.insert(id, values);
+                }
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// A deserialized [`FlightData`] message, as returned by [`deserialize_message_typed`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlightMessage {
+    /// A record batch message
+    Chunk(Chunk<Box<dyn Array>>),
+    /// A dictionary message, whose dictionary was upserted into the dictionaries
+    Dictionary,
+    /// A schema message, against which the subsequent messages are deserialized
+    Schema(Schema, IpcSchema),
+    /// An end-of-stream message (see [`is_end_of_stream`])
+    EndOfStream,
+}
+
 /// Deserializes [`FlightData`] into either a [`Chunk`] (when the message is a record batch)
 /// or by upserting into `dictionaries` (when the message is a dictionary)
+///
+/// See [`deserialize_dictionary`] for how `dictionaries` is updated. Use
+/// [`deserialize_message_typed`] to also deserialize schema and end-of-stream messages.
+/// # Errors
+/// This function errors on schema and end-of-stream messages (see [`is_end_of_stream`]),
+/// which [`deserialize_message_typed`] returns as [`FlightMessage::Schema`] and
+/// [`FlightMessage::EndOfStream`].
 pub fn deserialize_message(
     data: &FlightData,
     fields: &[Field],
     ipc_schema: &IpcSchema,
     dictionaries: &mut Dictionaries,
 ) -> Result<Option<Chunk<Box<dyn Array>>>> {
-    let FlightData {
-        data_header,
-        data_body,
-        ..
-    } = data;
+    match deserialize_message_typed(data, fields, ipc_schema, dictionaries)? {
+        FlightMessage::Chunk(chunk) => Ok(Some(chunk)),
+        FlightMessage::Dictionary => Ok(None),
+        FlightMessage::Schema(..) => Err(Error::nyi(
+            "Reading schema messages is only supported by `deserialize_message_typed`",
+        )),
+        FlightMessage::EndOfStream => Err(Error::oos(
+            "IPC Message must contain a header; end-of-stream messages are only supported by `deserialize_message_typed`",
+        )),
+    }
+}
 
-    let message = arrow_format::ipc::MessageRef::read_as_root(data_header)?;
+fn read_header(data_header: &[u8]) -> Result<(ipc::MetadataVersion, ipc::MessageHeaderRef)> {
+    let message = ipc::MessageRef::read_as_root(data_header)?;
+    let version = message.version()?;
     let header = message
         .header()?
         .ok_or_else(|| Error::oos("IPC Message must contain a header"))?;
+    Ok((version, header))
+}
+
+/// Deserializes [`FlightData`] into a [`FlightMessage`], so that a single function can
+/// deserialize every message of a stream.
+///
+/// `fields` and `ipc_schema` are only used to deserialize record batch and dictionary messages;
+/// a schema message returns the [`Schema`] and [`IpcSchema`] to use for the subsequent messages.
+/// See [`deserialize_dictionary`] for how `dictionaries` is updated.
+/// # Errors
+/// Errors are tagged with the [`FlightStage`] of the message (see [`stage_of`]), or with
+/// [`FlightStage::Header`] when its header cannot be read.
+pub fn deserialize_message_typed(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+) -> Result<FlightMessage> {
+    if is_end_of_stream(data) {
+        return Ok(FlightMessage::EndOfStream);
+    }
+
+    let (version, header) = read_header(&data.data_header).map_err(tag(FlightStage::Header))?;
+    deserialize_header(
+        version,
+        header,
+        &data.data_body,
+        fields,
+        ipc_schema,
+        dictionaries,
+        &mut Default::default(),
+    )
+}
 
+/// Deserializes a message from its `header` (as returned by [`read_header`]) and its `data_body`,
+/// decompressing through `scratch` so that it can be reused across messages.
+fn deserialize_header(
+    version: ipc::MetadataVersion,
+    header: ipc::MessageHeaderRef,
+    data_body: &[u8],
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+    scratch: &mut Vec<u8>,
+) -> Result<FlightMessage> {
     match header {
         ipc::MessageHeaderRef::RecordBatch(batch) => {
+            check_nodes(batch, fields).map_err(tag(FlightStage::Batch))?;
             let length = data_body.len();
             let mut reader = std::io::Cursor::new(data_body);
 
@@ -216,14 +917,15 @@ pub fn deserialize_message(
                 None,
                 None,
                 dictionaries,
-                arrow_format::ipc::MetadataVersion::V5,
+                version,
                 &mut reader,
                 0,
                 length as u64,
-                &mut Default::default(),
-            )?;
+                scratch,
+            )
+            .map_err(tag(FlightStage::Batch))?;
 
-            Ok(chunk.into())
+            Ok(FlightMessage::Chunk(chunk))
         }
         ipc::MessageHeaderRef::DictionaryBatch(dict_batch) => {
             let length = data_body.len();
@@ -237,12 +939,332 @@ pub fn deserialize_message(
                 &mut reader,
                 0,
                 length as u64,
-                &mut Default::default(),
-            )?;
-            Ok(None)
+                scratch,
+            )
+            .map_err(tag(FlightStage::Dictionary))?;
+            Ok(FlightMessage::Dictionary)
+        }
+        ipc::MessageHeaderRef::Schema(schema) => {
+            let (schema, ipc_schema) =
+                read::fb_to_schema(schema).map_err(tag(FlightStage::Schema))?;
+            Ok(FlightMessage::Schema(schema, ipc_schema))
+        }
+        t => Err(tag(FlightStage::Tensor)(Error::nyi(format!(
+            "Reading types other than schemas, record batches and dictionaries not yet supported, unable to read {t:?}"
+        )))),
+    }
+}
+
+/// A stateful deserializer of a stream of [`FlightData`] that tracks the most recent
+/// schema and dictionaries of the stream.
+///
+/// Schema messages replace the current schema (and discard the current dictionaries),
+/// dictionary messages update the current dictionaries, and record batch messages are
+/// deserialized against the current schema.
+///
+/// The buffer used to decompress the messages is reused across messages, like in
+/// [`StreamReader`](crate::io::ipc::read::StreamReader).
+///
+/// It does not depend on any async runtime, so that it composes with any stream combinator
+/// (e.g. `stream.map(|data| reader.decode(data?))`).
+#[derive(Debug, Default)]
+pub struct FlightStreamReader {
+    schema: Option<(Arc<Schema>, IpcSchema)>,
+    dictionaries: Dictionaries,
+    scratch: Vec<u8>,
+}
+
+impl FlightStreamReader {
+    /// Creates a new [`FlightStreamReader`] without a schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`FlightStreamReader`] whose schema is known upfront (e.g. from a
+    /// `GetSchema` response), as returned by [`deserialize_schemas`].
+    pub fn with_schema(schema: Schema, ipc_schema: IpcSchema) -> Self {
+        Self {
+            schema: Some((Arc::new(schema), ipc_schema)),
+            dictionaries: Default::default(),
+            scratch: Default::default(),
+        }
+    }
+
+    /// Returns the most recent schema of the stream, if any was read.
+    pub fn schema(&self) -> Option<&Arc<Schema>> {
+        self.schema.as_ref().map(|(schema, _)| schema)
+    }
+
+    /// Returns the most recent [`IpcSchema`] of the stream, if any was read.
+    pub fn ipc_schema(&self) -> Option<&IpcSchema> {
+        self.schema.as_ref().map(|(_, ipc_schema)| ipc_schema)
+    }
+
+    /// Deserializes [`FlightData`], returning the [`Chunk`] when the message is a record batch
+    /// and `None` otherwise.
+    ///
+    /// Use [`FlightStreamReader::decode_message`] to tell schema changes apart.
+    /// # Errors
+    /// See [`FlightStreamReader::decode_message`].
+    pub fn decode(&mut self, data: FlightData) -> Result<Option<Chunk<Box<dyn Array>>>> {
+        match self.decode_message(&data)? {
+            FlightMessage::Chunk(chunk) => Ok(Some(chunk)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Deserializes [`FlightData`] into a [`FlightMessage`], updating the schema and
+    /// dictionaries of this reader.
+    /// # Errors
+    /// This function errors iff the message fails to deserialize or a record batch or
+    /// dictionary message is read before any schema.
+    pub fn decode_message(&mut self, data: &FlightData) -> Result<FlightMessage> {
+        if is_end_of_stream(data) {
+            return Ok(FlightMessage::EndOfStream);
+        }
+        let (version, header) = read_header(&data.data_header).map_err(tag(FlightStage::Header))?;
+
+        if let ipc::MessageHeaderRef::Schema(schema) = header {
+            let (schema, ipc_schema) =
+                read::fb_to_schema(schema).map_err(tag(FlightStage::Schema))?;
+            self.schema = Some((Arc::new(schema.clone()), ipc_schema.clone()));
+            self.dictionaries.clear();
+            return Ok(FlightMessage::Schema(schema, ipc_schema));
+        }
+
+        let (schema, ipc_schema) = self
+            .schema
+            .as_ref()
+            .ok_or_else(|| Error::oos("A schema message must precede any other message"))?;
+        deserialize_header(
+            version,
+            header,
+            &data.data_body,
+            &schema.fields,
+            ipc_schema,
+            &mut self.dictionaries,
+            &mut self.scratch,
+        )
+    }
+
+    /// Deserializes [`FlightData`], returning the [`Chunk`] and the [`Schema`] it was
+    /// deserialized against when the message is a record batch, and `None` otherwise.
+    /// # Errors
+    /// See [`FlightStreamReader::decode_message`].
+    pub fn next_with_schema(
+        &mut self,
+        data: &FlightData,
+    ) -> Result<Option<(Chunk<Box<dyn Array>>, Arc<Schema>)>> {
+        match self.decode_message(data)? {
+            FlightMessage::Chunk(chunk) => Ok(self.schema().cloned().map(|schema| (chunk, schema))),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// A [`FlightStreamReader`] used to decode a stream of [`FlightData`], e.g. one whose schema is
+/// known upfront ([`FlightStreamReader::with_schema`]), with [`FlightStreamReader::decode`].
+pub type FlightStreamDecoder = FlightStreamReader;
+
+/// Writes a stream of [`FlightData`] to an Arrow IPC file, e.g. to buffer a stream for replay.
+///
+/// The messages are written as-is, without being deserialized. The first message must be a
+/// schema message; the file is only started once it arrives. Dictionary messages are written
+/// together with the record batch message that follows them; dictionaries not followed by a
+/// record batch are not written.
+/// A dictionary message identical to the one previously written with the same id is skipped.
+/// End-of-stream messages are ignored.
+///
+/// Since messages are not re-encoded, their bodies must be compressed as declared by the
+/// [`WriteOptions`] passed to [`FlightToIpcFileWriter::new`].
+pub struct FlightToIpcFileWriter<W: Write> {
+    /// The writer, until the schema message arrives
+    writer: Option<W>,
+    /// The file, once the schema message arrived
+    file: Option<write::FileWriter<W>>,
+    options: WriteOptions,
+    dictionaries: Vec<EncodedData>,
+    /// The first dictionary message of each id, or `None` once deltas were written to it
+    written_dictionaries: AHashMap<i64, Option<(Vec<u8>, Vec<u8>)>>,
+}
+
+impl<W: Write> FlightToIpcFileWriter<W> {
+    /// Creates a new [`FlightToIpcFileWriter`] that writes to `writer`.
+    pub fn new(writer: W, options: WriteOptions) -> Self {
+        Self {
+            writer: Some(writer),
+            file: None,
+            options,
+            dictionaries: vec![],
+            written_dictionaries: Default::default(),
+        }
+    }
+
+    /// Writes a [`FlightData`] message to the file.
+    /// # Errors
+    /// This function errors iff
+    /// * the first message is not a schema message or a schema message is not the first message
+    /// * writing the schema message failed before
+    /// * the message is neither a schema, dictionary or record batch message
+    /// * the compression of the message differs from the one of the [`WriteOptions`]
+    /// * a dictionary message replaces a previous dictionary, which the IPC file format
+    /// does not support (delta dictionaries are supported)
+    /// * the writer errors
+    pub fn write(&mut self, data: FlightData) -> Result<()> {
+        if is_end_of_stream(&data) {
+            return Ok(());
+        }
+        let message = ipc::MessageRef::read_as_root(&data.data_header)?;
+        let header = message
+            .header()?
+            .ok_or_else(|| Error::oos("IPC Message must contain a header"))?;
+
+        if let ipc::MessageHeaderRef::Schema(_) = &header {
+            let (schema, ipc_schema) = deserialize_schemas(&data.data_header)?;
+            let writer = self.writer.take().ok_or_else(|| {
+                Error::InvalidArgumentError(
+                    "An IPC file can only contain a single schema".to_string(),
+                )
+            })?;
+            // the file is kept even if starting it fails, so that the writer can be recovered
+            // with `into_inner` and the header is never written twice
+            let mut file =
+                write::FileWriter::new(writer, schema, Some(ipc_schema.fields), self.options);
+            let result = file.start();
+            self.file = Some(file);
+            return result;
+        }
+        let file = match &mut self.file {
+            None => {
+                return Err(Error::oos(
+                    "A schema message must precede any other message",
+                ))
+            }
+            Some(file) => file,
+        };
+        match file.state {
+            State::None => {
+                return Err(Error::InvalidArgumentError(
+                    "The IPC file failed to start and can no longer be written".to_string(),
+                ))
+            }
+            State::Finished => return Err(Error::oos("The IPC file was already finished")),
+            State::Started => {}
+        }
+
+        let is_batch = match header {
+            ipc::MessageHeaderRef::RecordBatch(batch) => {
+                check_compression(batch, self.options.compression)?;
+                true
+            }
+            ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+                let id = batch.id()?;
+                let is_delta = batch.is_delta()?;
+                let data_batch = batch
+                    .data()?
+                    .ok_or_else(|| Error::oos("The dictionary batch must contain data"))?;
+                check_compression(data_batch, self.options.compression)?;
+                match self.written_dictionaries.get(&id) {
+                    None => {
+                        let first = (!is_delta)
+                            .then(|| (data.data_header.clone(), data.data_body.clone()));
+                        self.written_dictionaries.insert(id, first);
+                    }
+                    Some(_) if is_delta => {
+                        self.written_dictionaries.insert(id, None);
+                    }
+                    Some(Some((first_header, first_body)))
+                        if *first_header == data.data_header && *first_body == data.data_body =>
+                    {
+                        return Ok(());
+                    }
+                    Some(_) => {
+                        return Err(Error::InvalidArgumentError(format!(
+                            "The dictionary with id {id} is replaced, which an IPC file does not support"
+                        )))
+                    }
+                }
+                false
+            }
+            t => {
+                return Err(Error::nyi(format!(
+                    "Writing messages other than schemas, dictionaries and record batches is not supported, unable to write {t:?}"
+                )))
+            }
+        };
+
+        let encoded = EncodedData {
+            ipc_message: data.data_header,
+            arrow_data: data.data_body,
+        };
+        if is_batch {
+            file.write_encoded(std::mem::take(&mut self.dictionaries), encoded)
+        } else {
+            self.dictionaries.push(encoded);
+            Ok(())
         }
-        t => Err(Error::nyi(format!(
-            "Reading types other than record batches not yet supported, unable to read {t:?}"
-        ))),
+    }
+
+    /// Writes the footer of the file.
+    pub fn finish(&mut self) -> Result<()> {
+        match &mut self.file {
+            Some(file) => file.finish(),
+            None => Err(Error::oos(
+                "A schema message must be written before finishing",
+            )),
+        }
+    }
+
+    /// Consumes itself into the inner writer.
+    pub fn into_inner(self) -> W {
+        match self.file {
+            Some(file) => file.into_inner(),
+            None => self.writer.unwrap(),
+        }
+    }
+}
+
+/// Checks that the body of `batch` is compressed with `expected`
+fn check_compression(batch: ipc::RecordBatchRef, expected: Option<Compression>) -> Result<()> {
+    let compression = match batch.compression()? {
+        Some(compression) => Some(match compression.codec()? {
+            ipc::CompressionType::Lz4Frame => Compression::LZ4,
+            ipc::CompressionType::Zstd => Compression::ZSTD,
+        }),
+        None => None,
+    };
+    if compression != expected {
+        return Err(Error::InvalidArgumentError(format!(
+            "The message is compressed with {compression:?} but the IPC file is written with {expected:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+    fn stream_reader_reuses_scratch() -> Result<()> {
+        let chunk = Chunk::new(vec![crate::array::Int32Array::from_slice([1, 2, 3]).boxed()]);
+        let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+        let fields = default_ipc_fields(&schema.fields);
+        let options = WriteOptions::new(Some(Compression::LZ4));
+        let (_, batch) = serialize_batch(&chunk, &fields, &options)?;
+
+        let mut reader = FlightStreamReader::new();
+        reader.decode_message(&serialize_schema(&schema, Some(&fields)))?;
+        assert_eq!(reader.scratch.capacity(), 0);
+
+        assert_eq!(reader.decode(batch.clone())?, Some(chunk.clone()));
+        let scratch = reader.scratch.as_ptr();
+        assert!(reader.scratch.capacity() > 0);
+
+        assert_eq!(reader.decode(batch)?, Some(chunk));
+        assert_eq!(reader.scratch.as_ptr(), scratch);
+        Ok(())
     }
 }
@@ -95,6 +95,11 @@ pub enum OutOfSpecKind {
         /// The requested dictionary id
         requested_id: i64,
     },
+    /// A delta dictionary batch was read before any dictionary batch with its id
+    MissingDeltaDictionary {
+        /// The requested dictionary id
+        requested_id: i64,
+    },
 }
 
 impl From<OutOfSpecKind> for Error {
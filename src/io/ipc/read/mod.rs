@@ -30,10 +30,12 @@ pub mod file_async;
 
 pub(crate) use common::first_dict_field;
 #[cfg(feature = "io_flight")]
-pub(crate) use common::{read_dictionary, read_record_batch};
+pub(crate) use common::{apply_projection, prepare_projection, read_dictionary, read_record_batch};
 pub use file::{read_batch, read_file_dictionaries, read_file_metadata, FileMetadata};
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
+#[cfg(feature = "io_flight")]
+pub(crate) use schema::fb_to_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
 
 /// how dictionaries are tracked in this crate
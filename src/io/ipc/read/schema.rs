@@ -373,7 +373,7 @@ pub fn deserialize_schema(message: &[u8]) -> Result<(Schema, IpcSchema)> {
 }
 
 /// Deserialize the raw Schema table from IPC format to Schema data type
-pub(super) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Schema, IpcSchema)> {
+pub(crate) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Schema, IpcSchema)> {
     let fields = schema
         .fields()?
         .ok_or_else(|| Error::from(OutOfSpecKind::MissingFields))?;
@@ -10,7 +10,7 @@ use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::write;
 
 /// Compression codec
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -21,12 +21,53 @@ pub enum Compression {
     ZSTD,
 }
 
-/// Options declaring the behaviour of writing to IPC
+/// Declares what is written when the values of a dictionary change between batches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DictionaryPolicy {
+    /// The new dictionary is written in full, replacing the previous one
+    Replace,
+    /// Writing errors
+    Error,
+    /// When the previous values are a prefix of the new values, only the new values are
+    /// written, as a delta dictionary batch. Otherwise, the new dictionary is written in full.
+    Delta,
+}
+
+impl Default for DictionaryPolicy {
+    fn default() -> Self {
+        Self::Replace
+    }
+}
+
+/// Options declaring the behaviour of writing to IPC, created with [`WriteOptions::new`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    dictionary_policy: DictionaryPolicy,
+}
+
+impl WriteOptions {
+    /// Creates new [`WriteOptions`] with `compression` and the default [`DictionaryPolicy`].
+    pub fn new(compression: Option<Compression>) -> Self {
+        Self {
+            compression,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the [`DictionaryPolicy`] of these [`WriteOptions`].
+    pub fn with_dictionary_policy(mut self, dictionary_policy: DictionaryPolicy) -> Self {
+        self.dictionary_policy = dictionary_policy;
+        self
+    }
+
+    /// Returns what is written when a dictionary changes between batches. It only applies to
+    /// writers that keep track of dictionaries across batches (e.g. [`StreamWriter`](super::StreamWriter)).
+    pub fn dictionary_policy(&self) -> DictionaryPolicy {
+        self.dictionary_policy
+    }
 }
 
 fn encode_dictionary(
@@ -44,7 +85,7 @@ fn encode_dictionary(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| Error::InvalidArgumentError("Dictionaries must have an associated id".to_string()))?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
+            let emit = dictionary_tracker.track(dict_id, array, options.dictionary_policy)?;
 
             let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
             let values = array.values();
@@ -55,13 +96,22 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes::<$T>(
+            match emit {
+                Emit::None => {}
+                Emit::Full => encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array,
+                    values.as_ref(),
+                    false,
                     options,
                     is_native_little_endian(),
-                ));
+                )),
+                Emit::Delta(offset) => encoded_dictionaries.push(dictionary_batch_to_bytes(
+                    dict_id,
+                    values.slice(offset, values.len() - offset).as_ref(),
+                    true,
+                    options,
+                    is_native_little_endian(),
+                )),
             };
             Ok(())
         }),
@@ -257,9 +307,10 @@ fn chunk_to_bytes(chunk: &Chunk<Box<dyn Array>>, options: &WriteOptions) -> Enco
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the data
-fn dictionary_batch_to_bytes<K: DictionaryKey>(
+fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &DictionaryArray<K>,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -267,16 +318,16 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
     );
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -291,7 +342,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
                     buffers: Some(buffers),
                     compression,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -307,6 +358,16 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
     }
 }
 
+/// What to emit for a dictionary, as decided by [`DictionaryTracker::track`]
+pub(crate) enum Emit {
+    /// Nothing, the dictionary was already emitted
+    None,
+    /// All values of the dictionary
+    Full,
+    /// The values of the dictionary from the given offset, as a delta
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
@@ -316,6 +377,15 @@ pub struct DictionaryTracker {
 }
 
 impl DictionaryTracker {
+    /// Creates a new [`DictionaryTracker`] that can replace dictionaries, with capacity
+    /// for `capacity` dictionaries so that tracking them does not re-allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            dictionaries: Dictionaries::with_capacity(capacity),
+            cannot_replace: false,
+        }
+    }
+
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
@@ -326,6 +396,20 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, array: &dyn Array) -> Result<bool> {
+        self.track(dict_id, array, DictionaryPolicy::Replace)
+            .map(|emit| !matches!(emit, Emit::None))
+    }
+
+    /// Keep track of the dictionary with the given ID and values, declaring what should be
+    /// emitted according to `policy` when the dictionary was already emitted with different values.
+    /// Errors if the dictionary changed and either `policy` is [`DictionaryPolicy::Error`] or this
+    /// tracker cannot replace dictionaries and a delta is not possible.
+    pub(crate) fn track(
+        &mut self,
+        dict_id: i64,
+        array: &dyn Array,
+        policy: DictionaryPolicy,
+    ) -> Result<Emit> {
         let values = match array.data_type() {
             DataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -340,22 +424,40 @@ impl DictionaryTracker {
         };
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.dictionaries.get(&dict_id) {
+        let emit = if let Some(last) = self.dictionaries.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
-            } else if self.cannot_replace {
-                return Err(Error::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+                return Ok(Emit::None);
+            }
+            let is_prefix = || {
+                last.len() <= values.len() && values.slice(0, last.len()).as_ref() == last.as_ref()
+            };
+            match policy {
+                DictionaryPolicy::Delta if is_prefix() => Emit::Delta(last.len()),
+                DictionaryPolicy::Replace | DictionaryPolicy::Delta if !self.cannot_replace => {
+                    Emit::Full
+                }
+                _ if self.cannot_replace => {
+                    return Err(Error::InvalidArgumentError(
+                        "Dictionary replacement detected when writing IPC file format. \
+                         Arrow IPC files only support a single dictionary for a given field \
+                         across all batches."
+                            .to_string(),
+                    ))
+                }
+                _ => {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "The dictionary with id {dict_id} changed between batches, which is not \
+                         allowed by DictionaryPolicy::Error"
+                    )))
+                }
             }
+        } else {
+            Emit::Full
         };
 
         self.dictionaries.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(emit)
     }
 }
 
@@ -425,3 +527,63 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_format::ipc::planus::ReadAsRoot;
+
+    #[test]
+    fn tracker_with_capacity() -> Result<()> {
+        let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+        let array =
+            DictionaryArray::try_from_keys(Int32Array::from_slice([0, 1, 0]), values.clone())?;
+        let chunk = Chunk::new(vec![array.clone().boxed(), array.boxed()]);
+        let fields = vec![
+            IpcField {
+                fields: vec![],
+                dictionary_id: Some(0),
+            },
+            IpcField {
+                fields: vec![],
+                dictionary_id: Some(1),
+            },
+        ];
+        let options = WriteOptions::default();
+
+        let mut tracker = DictionaryTracker::with_capacity(2);
+        let capacity = tracker.dictionaries.capacity();
+        assert!(capacity >= 2);
+        let (dictionaries, batch) = encode_chunk(&chunk, &fields, &mut tracker, &options)?;
+
+        // both dictionaries were tracked without re-allocating
+        assert_eq!(tracker.dictionaries.capacity(), capacity);
+        for id in [0, 1] {
+            assert_eq!(tracker.dictionaries[&id].as_ref(), values.as_ref());
+        }
+
+        // each dictionary is written in full, once, followed by the batch
+        let ids = dictionaries
+            .iter()
+            .map(|data| {
+                let message = arrow_format::ipc::MessageRef::read_as_root(&data.ipc_message)?;
+                match message.header()? {
+                    Some(arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch)) => {
+                        assert!(!batch.is_delta()?);
+                        Ok(batch.id()?)
+                    }
+                    other => panic!("expected a dictionary batch, got {other:?}"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(ids, vec![0, 1]);
+        let message = arrow_format::ipc::MessageRef::read_as_root(&batch.ipc_message)?;
+        match message.header()? {
+            Some(arrow_format::ipc::MessageHeaderRef::RecordBatch(batch)) => {
+                assert_eq!(batch.length()?, 3)
+            }
+            other => panic!("expected a record batch, got {other:?}"),
+        }
+        Ok(())
+    }
+}
@@ -5,10 +5,9 @@ mod serialize;
 mod stream;
 pub(crate) mod writer;
 
-pub use common::{Compression, Record, WriteOptions};
+pub use common::{Compression, DictionaryPolicy, Record, WriteOptions};
 pub use schema::schema_to_bytes;
 pub use serialize::write;
-pub(self) use serialize::write_dictionary;
 pub use stream::StreamWriter;
 pub use writer::FileWriter;
 
@@ -140,6 +140,15 @@ impl<W: Write> FileWriter<W> {
             &self.options,
         )?;
 
+        self.write_encoded(encoded_dictionaries, encoded_message)
+    }
+
+    /// Writes already encoded dictionaries and record batch messages to the file
+    pub(crate) fn write_encoded(
+        &mut self,
+        encoded_dictionaries: Vec<EncodedData>,
+        encoded_message: EncodedData,
+    ) -> Result<()> {
         // add all dictionaries
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) = write_message(&mut self.writer, encoded_dictionary)?;
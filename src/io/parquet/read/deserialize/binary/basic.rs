@@ -3,7 +3,7 @@ use std::default::Default;
 
 use parquet2::{
     deserialize::SliceFilteredIter,
-    encoding::{delta_length_byte_array, hybrid_rle, Encoding},
+    encoding::{delta_byte_array, delta_length_byte_array, hybrid_rle, Encoding},
     page::{split_buffer, DataPage, DictPage},
     schema::Repetition,
 };
@@ -88,6 +88,70 @@ impl<'a> Iterator for Delta<'a> {
     }
 }
 
+/// The values of a `DELTA_BYTE_ARRAY` page. Since each value is encoded as a suffix of
+/// the previous one, the values are reconstructed when the page is read.
+#[derive(Debug)]
+pub(super) struct DeltaBytes {
+    pub lengths: std::vec::IntoIter<usize>,
+    pub values: Vec<u8>,
+    /// The position in `values` of the next value
+    pub offset: usize,
+}
+
+impl DeltaBytes {
+    pub fn try_new(page: &DataPage) -> Result<Self> {
+        let (_, _, values) = split_buffer(page)?;
+
+        let mut prefixes_iter = delta_byte_array::Decoder::try_new(values)?;
+        #[allow(clippy::needless_collect)] // we need to consume it to get the suffixes
+        let prefixes = prefixes_iter
+            .by_ref()
+            .map(|x| x.map(|x| x as usize).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut suffixes_iter = prefixes_iter.into_lengths()?;
+        #[allow(clippy::needless_collect)] // we need to consume it to get the values
+        let suffixes = suffixes_iter
+            .by_ref()
+            .map(|x| x.map(|x| x as usize).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+        let mut suffix_values = suffixes_iter.into_values();
+
+        if prefixes.len() != suffixes.len() {
+            return Err(Error::oos(
+                "A DELTA_BYTE_ARRAY page must have as many prefixes as suffixes",
+            ));
+        }
+
+        let mut values = Vec::with_capacity(suffix_values.len());
+        let mut lengths = Vec::with_capacity(prefixes.len());
+        let mut previous = 0..0;
+        for (prefix, suffix) in prefixes.into_iter().zip(suffixes) {
+            if prefix > previous.len() || suffix > suffix_values.len() {
+                return Err(Error::oos(
+                    "A DELTA_BYTE_ARRAY page has a prefix or suffix longer than available",
+                ));
+            }
+            let start = values.len();
+            values.extend_from_within(previous.start..previous.start + prefix);
+            values.extend_from_slice(&suffix_values[..suffix]);
+            suffix_values = &suffix_values[suffix..];
+            lengths.push(prefix + suffix);
+            previous = start..values.len();
+        }
+
+        Ok(Self {
+            lengths: lengths.into_iter(),
+            values,
+            offset: 0,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.lengths.size_hint().0
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct FilteredRequired<'a> {
     pub values: SliceFilteredIter<SizedBinaryIter<'a>>,
@@ -198,6 +262,8 @@ enum State<'a> {
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a>),
     Delta(Delta<'a>),
     OptionalDelta(OptionalPageValidity<'a>, Delta<'a>),
+    DeltaBytes(DeltaBytes),
+    OptionalDeltaBytes(OptionalPageValidity<'a>, DeltaBytes),
     FilteredRequired(FilteredRequired<'a>),
     FilteredDelta(FilteredDelta<'a>),
     FilteredOptionalDelta(FilteredOptionalPageValidity<'a>, Delta<'a>),
@@ -213,6 +279,8 @@ impl<'a> utils::PageState<'a> for State<'a> {
             State::Required(state) => state.len(),
             State::Delta(state) => state.len(),
             State::OptionalDelta(state, _) => state.len(),
+            State::DeltaBytes(state) => state.len(),
+            State::OptionalDeltaBytes(state, _) => state.len(),
             State::RequiredDictionary(values) => values.len(),
             State::OptionalDictionary(optional, _) => optional.len(),
             State::FilteredRequired(state) => state.len(),
@@ -335,6 +403,13 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                 FilteredOptionalPageValidity::try_new(page)?,
                 Delta::try_new(page)?,
             )),
+            (Encoding::DeltaByteArray, _, false, false) => {
+                DeltaBytes::try_new(page).map(State::DeltaBytes)
+            }
+            (Encoding::DeltaByteArray, _, true, false) => Ok(State::OptionalDeltaBytes(
+                OptionalPageValidity::try_new(page)?,
+                DeltaBytes::try_new(page)?,
+            )),
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -370,25 +445,43 @@ impl<'a, O: Offset> utils::Decoder<'a> for BinaryDecoder<O> {
                 values.extend_lengths(page.lengths.by_ref().take(additional), &mut page.values);
             }
             State::OptionalDelta(page_validity, page_values) => {
-                let Binary {
-                    offsets,
-                    values: values_,
-                } = values;
-
-                let last_offset = *offsets.last();
+                let last_offset = *values.offsets.last();
                 extend_from_decoder(
                     validity,
                     page_validity,
                     Some(additional),
-                    offsets,
+                    &mut BinaryLengths(values),
                     page_values.lengths.by_ref(),
                 );
 
-                let length = *offsets.last() - last_offset;
+                let length = *values.offsets.last() - last_offset;
 
                 let (consumed, remaining) = page_values.values.split_at(length.to_usize());
                 page_values.values = remaining;
-                values_.extend_from_slice(consumed);
+                values.values.extend_from_slice(consumed);
+            }
+            State::DeltaBytes(page) => {
+                let mut remaining = &page.values[page.offset..];
+                let length = remaining.len();
+                values.extend_lengths(page.lengths.by_ref().take(additional), &mut remaining);
+                page.offset += length - remaining.len();
+            }
+            State::OptionalDeltaBytes(page_validity, page_values) => {
+                let last_offset = *values.offsets.last();
+                extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(additional),
+                    &mut BinaryLengths(values),
+                    page_values.lengths.by_ref(),
+                );
+
+                let length = (*values.offsets.last() - last_offset).to_usize();
+                let end = page_values.offset + length;
+                values
+                    .values
+                    .extend_from_slice(&page_values.values[page_values.offset..end]);
+                page_values.offset = end;
             }
             State::FilteredRequired(page) => {
                 for x in page.values.by_ref().take(additional) {
@@ -480,6 +573,9 @@ pub(super) fn finish<O: Offset, A: TraitBinaryArray<O>>(
     mut values: Binary<O>,
     mut validity: MutableBitmap,
 ) -> Result<A> {
+    if values.overflowed() {
+        return Err(overflow_error(data_type));
+    }
     values.offsets.shrink_to_fit();
     values.values.shrink_to_fit();
     validity.shrink_to_fit();
@@ -12,7 +12,7 @@ use crate::{
 };
 
 use super::super::Pages;
-use super::{super::dictionary::*, utils::SizedBinaryIter};
+use super::{super::dictionary::*, utils::overflow_error, utils::SizedBinaryIter};
 use super::{super::utils::MaybeNext, utils::Binary};
 
 /// An iterator adapter over [`Pages`] assumed to be encoded as parquet's dictionary-encoded binary representation
@@ -51,7 +51,7 @@ where
     }
 }
 
-fn read_dict<O: Offset>(data_type: DataType, dict: &DictPage) -> Box<dyn Array> {
+fn read_dict<O: Offset>(data_type: DataType, dict: &DictPage) -> Result<Box<dyn Array>> {
     let data_type = match data_type {
         DataType::Dictionary(_, values, _) => *values,
         _ => data_type,
@@ -64,8 +64,11 @@ fn read_dict<O: Offset>(data_type: DataType, dict: &DictPage) -> Box<dyn Array>
     for item in values {
         data.push(item)
     }
+    if data.overflowed() {
+        return Err(overflow_error(&data_type));
+    }
 
-    match data_type.to_physical_type() {
+    Ok(match data_type.to_physical_type() {
         PhysicalType::Utf8 | PhysicalType::LargeUtf8 => {
             Utf8Array::<O>::new(data_type, data.offsets.into(), data.values.into(), None).boxed()
         }
@@ -73,7 +76,7 @@ fn read_dict<O: Offset>(data_type: DataType, dict: &DictPage) -> Box<dyn Array>
             BinaryArray::<O>::new(data_type, data.offsets.into(), data.values.into(), None).boxed()
         }
         _ => unreachable!(),
-    }
+    })
 }
 
 impl<K, O, I> Iterator for DictIter<K, O, I>
@@ -6,3 +6,5 @@ mod utils;
 pub use basic::Iter;
 pub use dictionary::{DictIter, NestedDictIter};
 pub use nested::NestedIter;
+pub(super) use utils::name_overflow_error;
+pub use utils::OffsetOverflowError;
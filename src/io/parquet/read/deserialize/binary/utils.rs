@@ -1,36 +1,95 @@
-use crate::offset::{Offset, Offsets};
+use crate::{
+    datatypes::DataType,
+    error::Error,
+    offset::{Offset, Offsets},
+};
 
 use super::super::utils::Pushable;
 
+/// The error of reading a column whose values do not fit in the offsets of its data type
+/// (e.g. more than `i32::MAX` bytes read as [`DataType::Utf8`]), returned wrapped in
+/// [`Error::External`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetOverflowError {
+    /// The name of the column
+    pub column: String,
+    /// The data type the values were read as
+    pub data_type: DataType,
+}
+
+impl std::fmt::Display for OffsetOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The values of the column \"{}\" do not fit in the offsets of {:?}; read it as LargeUtf8 or LargeBinary instead",
+            self.column, self.data_type,
+        )
+    }
+}
+
+impl std::error::Error for OffsetOverflowError {}
+
+/// The [`OffsetOverflowError`] of values read as `data_type`, whose column is named
+/// by [`name_overflow_error`].
+pub(super) fn overflow_error(data_type: &DataType) -> Error {
+    Error::from_external_error(OffsetOverflowError {
+        column: String::new(),
+        data_type: data_type.clone(),
+    })
+}
+
+/// Sets the column of `error` to `column` when it is an [`OffsetOverflowError`].
+pub(crate) fn name_overflow_error(mut error: Error, column: &str) -> Error {
+    if let Error::External(_, source) = &mut error {
+        if let Some(overflow) = source.downcast_mut::<OffsetOverflowError>() {
+            overflow.column = column.to_string();
+        }
+    }
+    error
+}
+
 /// [`Pushable`] for variable length binary data.
+///
+/// When the values no longer fit in offsets of type `O`, the remaining items are
+/// pushed as empty slots and [`Binary::overflowed`] is set, so that the column
+/// can be rejected with an error instead of panicking mid-page.
 #[derive(Debug)]
 pub struct Binary<O: Offset> {
     pub offsets: Offsets<O>,
     pub values: Vec<u8>,
+    overflowed: bool,
 }
 
-impl<O: Offset> Pushable<usize> for Offsets<O> {
+/// [`Pushable`] of the lengths of a [`Binary`], used when lengths and values are
+/// decoded separately (e.g. `DeltaLengthByteArray`).
+#[derive(Debug)]
+pub struct BinaryLengths<'a, O: Offset>(pub &'a mut Binary<O>);
+
+impl<'a, O: Offset> Pushable<usize> for BinaryLengths<'a, O> {
     fn reserve(&mut self, additional: usize) {
-        self.reserve(additional)
+        self.0.offsets.reserve(additional)
     }
     #[inline]
     fn len(&self) -> usize {
-        self.len_proxy()
+        self.0.offsets.len_proxy()
     }
 
     #[inline]
     fn push(&mut self, value: usize) {
-        self.try_push_usize(value).unwrap()
+        if self.0.overflowed || self.0.offsets.try_push_usize(value).is_err() {
+            self.0.overflowed = true;
+            self.0.offsets.extend_constant(1);
+        }
     }
 
     #[inline]
     fn push_null(&mut self) {
-        self.extend_constant(1);
+        self.0.offsets.extend_constant(1);
     }
 
     #[inline]
     fn extend_constant(&mut self, additional: usize, _: usize) {
-        self.extend_constant(additional)
+        self.0.offsets.extend_constant(additional)
     }
 }
 
@@ -40,9 +99,16 @@ impl<O: Offset> Binary<O> {
         Self {
             offsets: Offsets::with_capacity(capacity),
             values: Vec::with_capacity(capacity.min(100) * 24),
+            overflowed: false,
         }
     }
 
+    /// Whether the values pushed so far overflowed offsets of type `O`
+    #[inline]
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
     #[inline]
     pub fn push(&mut self, v: &[u8]) {
         if self.offsets.len_proxy() == 100 && self.offsets.capacity() > 100 {
@@ -53,8 +119,12 @@ impl<O: Offset> Binary<O> {
             }
         }
 
+        if self.overflowed || self.offsets.try_push_usize(v.len()).is_err() {
+            self.overflowed = true;
+            self.offsets.extend_constant(1);
+            return;
+        }
         self.values.extend(v);
-        self.offsets.try_push_usize(v.len()).unwrap()
     }
 
     #[inline]
@@ -69,10 +139,15 @@ impl<O: Offset> Binary<O> {
 
     #[inline]
     pub fn extend_lengths<I: Iterator<Item = usize>>(&mut self, lengths: I, values: &mut &[u8]) {
-        let current_offset = *self.offsets.last();
-        self.offsets.try_extend_from_lengths(lengths).unwrap();
-        let new_offset = *self.offsets.last();
-        let length = new_offset.to_usize() - current_offset.to_usize();
+        let mut length = 0;
+        for item in lengths {
+            if self.overflowed || self.offsets.try_push_usize(item).is_err() {
+                self.overflowed = true;
+                self.offsets.extend_constant(1);
+            } else {
+                length += item;
+            }
+        }
         let (consumed, remaining) = values.split_at(length);
         *values = remaining;
         self.values.extend_from_slice(consumed);
@@ -168,3 +243,21 @@ impl<'a> Iterator for SizedBinaryIter<'a> {
         (self.remaining, Some(self.remaining))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_overflow_error() {
+        let error = name_overflow_error(overflow_error(&DataType::Utf8), "a");
+        assert_eq!(
+            error.to_string(),
+            "External error: The values of the column \"a\" do not fit in the offsets of Utf8; read it as LargeUtf8 or LargeBinary instead"
+        );
+
+        // other errors are left untouched
+        let error = name_overflow_error(Error::Overflow, "a");
+        assert!(matches!(error, Error::Overflow));
+    }
+}
@@ -239,7 +239,7 @@ fn finish_key<K: DictionaryKey>(values: Vec<K>, validity: MutableBitmap) -> Prim
 }
 
 #[inline]
-pub(super) fn next_dict<K: DictionaryKey, I: Pages, F: Fn(&DictPage) -> Box<dyn Array>>(
+pub(super) fn next_dict<K: DictionaryKey, I: Pages, F: Fn(&DictPage) -> Result<Box<dyn Array>>>(
     iter: &mut I,
     items: &mut VecDeque<(Vec<K>, MutableBitmap)>,
     dict: &mut Option<Box<dyn Array>>,
@@ -267,7 +267,10 @@ pub(super) fn next_dict<K: DictionaryKey, I: Pages, F: Fn(&DictPage) -> Box<dyn
                     )));
                 }
                 (_, Page::Dict(dict_page)) => {
-                    *dict = Some(read_dict(dict_page));
+                    match read_dict(dict_page) {
+                        Ok(values) => *dict = Some(values),
+                        Err(e) => return MaybeNext::Some(Err(e)),
+                    }
                     return next_dict(
                         iter, items, dict, data_type, remaining, chunk_size, read_dict,
                     );
@@ -22,7 +22,10 @@ use crate::{
 };
 
 use self::nested_utils::{InitNested, NestedArrayIter, NestedState};
+pub use binary::OffsetOverflowError;
 use simple::page_iter_to_arrays;
+pub(super) use utils::is_unsupported_encoding;
+pub use utils::UnsupportedEncodingError;
 
 use super::*;
 
@@ -162,6 +165,8 @@ fn n_columns(data_type: &DataType) -> usize {
 /// For nested types, `columns` must be composed by all parquet columns with associated types `types`.
 ///
 /// The arrays are guaranteed to be at most of size `chunk_size` and data type `field.data_type`.
+/// Values that do not fit in the offsets of their data type error with an
+/// [`OffsetOverflowError`] naming `field`.
 pub fn column_iter_to_arrays<'a, I: 'a>(
     columns: Vec<I>,
     types: Vec<&PrimitiveType>,
@@ -172,8 +177,13 @@ pub fn column_iter_to_arrays<'a, I: 'a>(
 where
     I: Pages,
 {
+    let name = field.name.clone();
     Ok(Box::new(
-        columns_to_iter_recursive(columns, types, field, vec![], num_rows, chunk_size)?
-            .map(|x| x.map(|x| x.1)),
+        columns_to_iter_recursive(columns, types, field, vec![], num_rows, chunk_size)?.map(
+            move |x| {
+                x.map(|x| x.1)
+                    .map_err(|error| binary::name_overflow_error(error, &name))
+            },
+        ),
     ))
 }
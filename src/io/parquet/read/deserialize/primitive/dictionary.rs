@@ -99,7 +99,7 @@ where
             self.data_type.clone(),
             &mut self.remaining,
             self.chunk_size,
-            |dict| read_dict::<P, T, _>(self.data_type.clone(), self.op, dict),
+            |dict| Ok(read_dict::<P, T, _>(self.data_type.clone(), self.op, dict)),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
@@ -181,7 +181,7 @@ where
             &mut self.values,
             self.data_type.clone(),
             self.chunk_size,
-            |dict| read_dict::<P, T, _>(self.data_type.clone(), self.op, dict),
+            |dict| Ok(read_dict::<P, T, _>(self.data_type.clone(), self.op, dict)),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
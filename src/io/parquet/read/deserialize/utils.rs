@@ -3,9 +3,10 @@ use std::collections::VecDeque;
 use parquet2::deserialize::{
     FilteredHybridEncoded, FilteredHybridRleDecoderIter, HybridDecoderBitmapIter, HybridEncoded,
 };
-use parquet2::encoding::hybrid_rle;
+use parquet2::encoding::{hybrid_rle, Encoding};
 use parquet2::indexes::Interval;
 use parquet2::page::{split_buffer, DataPage, DictPage, Page};
+use parquet2::schema::types::PhysicalType;
 use parquet2::schema::Repetition;
 
 use crate::bitmap::utils::BitmapIter;
@@ -14,18 +15,55 @@ use crate::error::Error;
 
 use super::super::Pages;
 
+/// The error of decoding a parquet page whose encoding is not supported by this crate,
+/// returned wrapped in [`Error::External`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedEncodingError {
+    /// The physical type of the page
+    pub physical_type: PhysicalType,
+    /// The encoding of the page
+    pub encoding: Encoding,
+    /// Whether the page is of an optional column
+    pub is_optional: bool,
+    /// Whether the page is filtered by the page index
+    pub is_filtered: bool,
+}
+
+impl std::fmt::Display for UnsupportedEncodingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let required = if self.is_optional {
+            "optional"
+        } else {
+            "required"
+        };
+        let is_filtered = if self.is_filtered {
+            ", index-filtered"
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "Decoding {:?} \"{:?}\"-encoded {}{} parquet pages is not yet implemented",
+            self.physical_type, self.encoding, required, is_filtered,
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedEncodingError {}
+
 pub fn not_implemented(page: &DataPage) -> Error {
-    let is_optional = page.descriptor.primitive_type.field_info.repetition == Repetition::Optional;
-    let is_filtered = page.selected_rows().is_some();
-    let required = if is_optional { "optional" } else { "required" };
-    let is_filtered = if is_filtered { ", index-filtered" } else { "" };
-    Error::NotYetImplemented(format!(
-        "Decoding {:?} \"{:?}\"-encoded {} {} parquet pages",
-        page.descriptor.primitive_type.physical_type,
-        page.encoding(),
-        required,
-        is_filtered,
-    ))
+    Error::from_external_error(UnsupportedEncodingError {
+        physical_type: page.descriptor.primitive_type.physical_type,
+        encoding: page.encoding(),
+        is_optional: page.descriptor.primitive_type.field_info.repetition == Repetition::Optional,
+        is_filtered: page.selected_rows().is_some(),
+    })
+}
+
+/// Returns whether `error` was returned by [`not_implemented`], i.e. whether a page
+/// failed to deserialize because its encoding is not supported.
+pub(crate) fn is_unsupported_encoding(error: &Error) -> bool {
+    matches!(error, Error::External(_, error) if error.is::<UnsupportedEncodingError>())
 }
 
 /// A private trait representing structs that can receive elements.
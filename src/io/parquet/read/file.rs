@@ -8,7 +8,10 @@ use crate::datatypes::Schema;
 use crate::error::Result;
 use crate::io::parquet::read::read_columns_many;
 
-use super::{RowGroupDeserializer, RowGroupMetaData};
+use super::{
+    null_on_unsupported_encoding, ReadOptions, RowGroupDeserializer, RowGroupMetaData,
+    UnsupportedEncoding,
+};
 
 /// An iterator of [`Chunk`]s coming from row groups of a parquet file.
 ///
@@ -43,6 +46,12 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Sets the [`ReadOptions`] used to read the remaining row groups.
+    pub fn with_options(mut self, options: ReadOptions) -> Self {
+        self.row_groups.options = options;
+        self
+    }
+
     fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
         let result = self.row_groups.next().transpose()?;
 
@@ -118,6 +127,7 @@ pub struct RowGroupReader<R: Read + Seek> {
     chunk_size: Option<usize>,
     remaining_rows: usize,
     page_indexes: Option<std::vec::IntoIter<Vec<Vec<Vec<FilteredPage>>>>>,
+    options: ReadOptions,
 }
 
 impl<R: Read + Seek> RowGroupReader<R> {
@@ -140,9 +150,16 @@ impl<R: Read + Seek> RowGroupReader<R> {
             chunk_size,
             remaining_rows: limit.unwrap_or(usize::MAX),
             page_indexes: page_indexes.map(|pages| pages.into_iter()),
+            options: Default::default(),
         }
     }
 
+    /// Sets the [`ReadOptions`] used to read the remaining row groups.
+    pub fn with_options(mut self, options: ReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
@@ -187,6 +204,26 @@ impl<R: Read + Seek> RowGroupReader<R> {
             pages,
         )?;
 
+        let column_chunks = match self.options.on_unsupported_encoding {
+            UnsupportedEncoding::Error => column_chunks,
+            UnsupportedEncoding::SkipColumn => column_chunks
+                .into_iter()
+                .zip(self.schema.fields.iter())
+                .map(|(iter, field)| {
+                    if field.is_nullable {
+                        null_on_unsupported_encoding(
+                            iter,
+                            field.data_type.clone(),
+                            num_rows.min(self.remaining_rows),
+                            self.chunk_size,
+                        )
+                    } else {
+                        iter
+                    }
+                })
+                .collect(),
+        };
+
         let result = RowGroupDeserializer::new(column_chunks, num_rows, Some(self.remaining_rows));
         self.remaining_rows = self.remaining_rows.saturating_sub(num_rows);
         Ok(Some(result))
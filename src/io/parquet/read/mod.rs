@@ -32,12 +32,14 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-use crate::{array::Array, error::Result};
+use crate::{array::Array, datatypes::Field, error::Result};
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+pub use deserialize::{
+    column_iter_to_arrays, get_page_iterator, OffsetOverflowError, UnsupportedEncodingError,
+};
 pub use file::{FileReader, RowGroupReader};
 pub use row_group::*;
-pub use schema::{infer_schema, FileMetaData};
+pub use schema::{infer_field_types, infer_schema, ColumnTypes, FileMetaData};
 
 /// Trait describing a [`FallibleStreamingIterator`] of [`Page`]
 pub trait Pages:
@@ -50,11 +52,92 @@ impl<I: FallibleStreamingIterator<Item = Page, Error = ParquetError> + Send + Sy
 /// Type def for a sharable, boxed dyn [`Iterator`] of arrays
 pub type ArrayIter<'a> = Box<dyn Iterator<Item = Result<Box<dyn Array>>> + Send + Sync + 'a>;
 
+/// Declares how a reader handles a column containing a page whose encoding is not supported
+/// by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnsupportedEncoding {
+    /// Reading the column errors
+    Error,
+    /// The column is read as nulls from the first unsupported page onwards,
+    /// so that the remaining columns can still be read. Columns of non-nullable fields
+    /// cannot be null and still error.
+    SkipColumn,
+}
+
+impl Default for UnsupportedEncoding {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+/// Options declaring the behaviour of reading from parquet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReadOptions {
+    /// How to handle pages whose encoding is not supported
+    pub on_unsupported_encoding: UnsupportedEncoding,
+}
+
 /// Reads parquets' metadata syncronously.
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads the statistics of every field of a parquet file from its footer, without reading
+/// any page. Each [`Statistics`](statistics::Statistics) has one entry per row group.
+///
+/// This is useful to prune row groups before reading them.
+pub fn read_statistics_only<R: Read + Seek>(
+    reader: &mut R,
+) -> Result<Vec<(Field, statistics::Statistics)>> {
+    let metadata = read_metadata(reader)?;
+    let schema = infer_schema(&metadata)?;
+    schema
+        .fields
+        .into_iter()
+        .map(|field| {
+            let statistics = statistics::deserialize(&field, &metadata.row_groups)?;
+            Ok((field, statistics))
+        })
+        .collect()
+}
+
+/// Returns a [`FileReader`] of the row groups of a parquet file whose statistics satisfy
+/// `predicate`, e.g. to skip row groups that can't match a filter of a scan.
+///
+/// `predicate` is called once per row group with the statistics of every field of the
+/// row group, as returned by [`read_statistics_only`] (i.e. with a single entry each).
+pub fn read_with_row_group_filter<R, F>(
+    mut reader: R,
+    predicate: F,
+    chunk_size: Option<usize>,
+) -> Result<FileReader<R>>
+where
+    R: Read + Seek,
+    F: Fn(&[(Field, statistics::Statistics)]) -> bool,
+{
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let mut row_groups = vec![];
+    for row_group in metadata.row_groups {
+        let row_group_statistics = schema
+            .fields
+            .iter()
+            .map(|field| {
+                let statistics = statistics::deserialize(field, std::slice::from_ref(&row_group))?;
+                Ok((field.clone(), statistics))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if predicate(&row_group_statistics) {
+            row_groups.push(row_group);
+        }
+    }
+
+    Ok(FileReader::new(
+        reader, row_groups, schema, chunk_size, None, None,
+    ))
+}
+
 /// Reads parquets' metadata asynchronously.
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     reader: &mut R,
@@ -11,10 +11,14 @@ use parquet2::{
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::{new_null_array, Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field},
+    error::Result,
     io::parquet::read::column_iter_to_arrays,
 };
 
+use super::deserialize::is_unsupported_encoding;
 use super::ArrayIter;
 use super::RowGroupMetaData;
 
@@ -80,6 +84,59 @@ impl Iterator for RowGroupDeserializer {
     }
 }
 
+/// An [`Iterator`] of [`Array`] that yields arrays of nulls once its inner iterator errors
+/// on a page whose encoding is not supported.
+struct NullOnUnsupported<'a> {
+    iter: ArrayIter<'a>,
+    data_type: DataType,
+    chunk_size: usize,
+    remaining: usize,
+    is_unsupported: bool,
+}
+
+impl<'a> Iterator for NullOnUnsupported<'a> {
+    type Item = Result<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_unsupported {
+            match self.iter.next() {
+                Some(Ok(array)) => {
+                    self.remaining = self.remaining.saturating_sub(array.len());
+                    return Some(Ok(array));
+                }
+                Some(Err(error)) if is_unsupported_encoding(&error) => self.is_unsupported = true,
+                other => return other,
+            }
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        let length = self.chunk_size.min(self.remaining);
+        self.remaining -= length;
+        Some(Ok(new_null_array(self.data_type.clone(), length)))
+    }
+}
+
+/// Wraps an [`ArrayIter`] of `num_rows` rows in chunks of `chunk_size` (as returned by
+/// [`to_deserializer`]) such that, once it errors because the encoding of a page is not
+/// supported, the remaining chunks are arrays of nulls of type `data_type`.
+/// Any other error is returned as is.
+pub(crate) fn null_on_unsupported_encoding<'a>(
+    iter: ArrayIter<'a>,
+    data_type: DataType,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+) -> ArrayIter<'a> {
+    let chunk_size = chunk_size.map(|c| c.min(num_rows)).unwrap_or(num_rows);
+    Box::new(NullOnUnsupported {
+        iter,
+        data_type,
+        chunk_size,
+        remaining: num_rows,
+        is_unsupported: false,
+    })
+}
+
 /// Returns all [`ColumnChunkMetaData`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
 pub fn get_field_columns<'a>(
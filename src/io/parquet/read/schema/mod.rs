@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
+use crate::datatypes::{Field, Schema};
 use crate::error::Result;
 
 mod convert;
@@ -9,6 +9,7 @@ pub use convert::parquet_to_arrow_schema;
 pub use metadata::read_schema_from_metadata;
 pub use parquet2::metadata::{FileMetaData, KeyValue, SchemaDescriptor};
 pub use parquet2::schema::types::ParquetType;
+use parquet2::schema::types::{PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType};
 
 pub(crate) use convert::*;
 
@@ -29,3 +30,48 @@ pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
         Schema { fields, metadata }
     }))
 }
+
+/// The parquet types of a leaf column, as declared in the file's parquet schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnTypes {
+    /// The path of the column in the parquet schema
+    pub path_in_schema: Vec<String>,
+    /// The physical type of the column
+    pub physical_type: PhysicalType,
+    /// The logical type annotation of the column, if any
+    pub logical_type: Option<PrimitiveLogicalType>,
+    /// The (legacy) converted type annotation of the column, if any
+    pub converted_type: Option<PrimitiveConvertedType>,
+}
+
+/// Returns, for each field of the [`Schema`] inferred by [`infer_schema`], the field and the
+/// parquet types of its leaf columns.
+///
+/// This is useful to diagnose why a field is read to an unexpected arrow [`DataType`](crate::datatypes::DataType).
+/// # Error
+/// This function errors iff [`infer_schema`] errors.
+pub fn infer_field_types(file_metadata: &FileMetaData) -> Result<Vec<(Field, Vec<ColumnTypes>)>> {
+    let schema = infer_schema(file_metadata)?;
+    Ok(schema
+        .fields
+        .into_iter()
+        .map(|field| {
+            let columns = file_metadata
+                .schema()
+                .columns()
+                .iter()
+                .filter(|column| column.path_in_schema[0] == field.name)
+                .map(|column| {
+                    let primitive_type = &column.descriptor.primitive_type;
+                    ColumnTypes {
+                        path_in_schema: column.path_in_schema.clone(),
+                        physical_type: primitive_type.physical_type,
+                        logical_type: primitive_type.logical_type,
+                        converted_type: primitive_type.converted_type,
+                    }
+                })
+                .collect();
+            (field, columns)
+        })
+        .collect())
+}
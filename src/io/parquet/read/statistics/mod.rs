@@ -39,9 +39,9 @@ pub struct Statistics {
     pub null_count: Box<dyn Array>,
     /// number of dictinct values. This is a [`UInt64Array`] for non-nested types
     pub distinct_count: Box<dyn Array>,
-    /// Minimum
+    /// Minimum. A row group whose statistics only declare a maximum has a null minimum.
     pub min_value: Box<dyn Array>,
-    /// Maximum
+    /// Maximum. A row group whose statistics only declare a minimum has a null maximum.
     pub max_value: Box<dyn Array>,
 }
 
@@ -547,3 +547,93 @@ pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Sta
 
     Ok(statistics.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_statistics(min_value: Option<&str>, max_value: Option<&str>) -> BinaryStatistics {
+        BinaryStatistics {
+            primitive_type: ParquetPrimitiveType::from_physical(
+                "a".to_string(),
+                ParquetPhysicalType::ByteArray,
+            ),
+            null_count: Some(0),
+            distinct_count: None,
+            min_value: min_value.map(|x| x.as_bytes().to_vec()),
+            max_value: max_value.map(|x| x.as_bytes().to_vec()),
+        }
+    }
+
+    fn primitive_statistics(
+        min_value: Option<i32>,
+        max_value: Option<i32>,
+    ) -> PrimitiveStatistics<i32> {
+        PrimitiveStatistics {
+            primitive_type: ParquetPrimitiveType::from_physical(
+                "a".to_string(),
+                ParquetPhysicalType::Int32,
+            ),
+            null_count: Some(0),
+            distinct_count: None,
+            min_value,
+            max_value,
+        }
+    }
+
+    fn utf8_bounds(from: &dyn ParquetStatistics) -> (Utf8Array<i32>, Utf8Array<i32>) {
+        let mut min = MutableUtf8Array::<i32>::new();
+        let mut max = MutableUtf8Array::<i32>::new();
+        utf8::push::<i32>(Some(from), &mut min, &mut max).unwrap();
+        (min.into(), max.into())
+    }
+
+    fn int32_bounds(from: &dyn ParquetStatistics) -> (Int32Array, Int32Array) {
+        let mut min = MutablePrimitiveArray::<i32>::new();
+        let mut max = MutablePrimitiveArray::<i32>::new();
+        primitive::push::<i32, i32, _>(Some(from), &mut min, &mut max, Ok).unwrap();
+        (min.into(), max.into())
+    }
+
+    #[test]
+    fn utf8_max_only() {
+        let (min, max) = utf8_bounds(&binary_statistics(None, Some("b")));
+        assert_eq!(min, Utf8Array::<i32>::from([None::<&str>]));
+        assert_eq!(max, Utf8Array::<i32>::from([Some("b")]));
+    }
+
+    #[test]
+    fn utf8_min_only() {
+        let (min, max) = utf8_bounds(&binary_statistics(Some("a"), None));
+        assert_eq!(min, Utf8Array::<i32>::from([Some("a")]));
+        assert_eq!(max, Utf8Array::<i32>::from([None::<&str>]));
+    }
+
+    #[test]
+    fn utf8_min_and_max() {
+        let (min, max) = utf8_bounds(&binary_statistics(Some("a"), Some("b")));
+        assert_eq!(min, Utf8Array::<i32>::from([Some("a")]));
+        assert_eq!(max, Utf8Array::<i32>::from([Some("b")]));
+    }
+
+    #[test]
+    fn primitive_max_only() {
+        let (min, max) = int32_bounds(&primitive_statistics(None, Some(2)));
+        assert_eq!(min, Int32Array::from([None]));
+        assert_eq!(max, Int32Array::from([Some(2)]));
+    }
+
+    #[test]
+    fn primitive_min_only() {
+        let (min, max) = int32_bounds(&primitive_statistics(Some(1), None));
+        assert_eq!(min, Int32Array::from([Some(1)]));
+        assert_eq!(max, Int32Array::from([None]));
+    }
+
+    #[test]
+    fn primitive_min_and_max() {
+        let (min, max) = int32_bounds(&primitive_statistics(Some(1), Some(2)));
+        assert_eq!(min, Int32Array::from([Some(1)]));
+        assert_eq!(max, Int32Array::from([Some(2)]));
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{Error, Result};
+use crate::io::parquet::read::{infer_schema, read_metadata};
+
+use super::{write_metadata_sidecar, Encoding, FileWriter, RowGroupIterator, WriteOptions};
+
+/// Returns the position at which the footer of a parquet file starts.
+fn footer_start<R: Read + Seek>(reader: &mut R) -> Result<u64> {
+    let size = reader.seek(SeekFrom::End(-8))? + 8;
+    let mut metadata_length = [0; 4];
+    reader.read_exact(&mut metadata_length)?;
+    let metadata_length = u32::from_le_bytes(metadata_length) as u64;
+    size.checked_sub(8 + metadata_length)
+        .ok_or_else(|| Error::oos("The footer length is larger than the file"))
+}
+
+/// Appends `chunks` as new row groups to the existing parquet file `file`.
+///
+/// The new row groups are written at the end of `file`, followed by a new footer declaring
+/// both the existing and the new row groups; the existing footer is left in place, unused.
+/// Since no existing byte of `file` is overwritten, `file` can be restored by truncating it
+/// to its original size should appending fail midway. Returns the new size of the file.
+/// # Errors
+/// Iff
+/// * `file` is not a valid parquet file
+/// * `schema` is different from the schema of `file`
+/// * any of the chunks fails to be written (see [`RowGroupIterator`])
+pub fn append_row_groups<F, I>(
+    file: &mut F,
+    schema: &Schema,
+    chunks: I,
+    options: WriteOptions,
+    encodings: Vec<Vec<Encoding>>,
+) -> Result<u64>
+where
+    F: Read + Write + Seek,
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    let metadata = read_metadata(file)?;
+    if infer_schema(&metadata)?.fields != schema.fields {
+        return Err(Error::InvalidArgumentError(
+            "The schema of the row groups to append must equal the schema of the file".to_string(),
+        ));
+    }
+    let size = file.seek(SeekFrom::End(0))?;
+
+    // write the new row groups (and their page indexes) as a standalone file
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), options)?;
+    for group in RowGroupIterator::try_new(chunks, schema, options, encodings)? {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let (data, new_metadata) = writer.into_inner_and_metadata();
+    let data_end = footer_start(&mut Cursor::new(&data))? as usize;
+
+    // the new data is placed after the end of the file, minus the magic of the standalone file
+    let shift = size as i64 - 4;
+    let mut metadata = metadata.into_thrift();
+    for mut row_group in new_metadata.row_groups {
+        row_group.file_offset = row_group.file_offset.map(|x| x + shift);
+        for column in row_group.columns.iter_mut() {
+            column.file_offset += shift;
+            column.offset_index_offset = column.offset_index_offset.map(|x| x + shift);
+            column.column_index_offset = column.column_index_offset.map(|x| x + shift);
+            if let Some(meta) = column.meta_data.as_mut() {
+                meta.data_page_offset += shift;
+                meta.index_page_offset = meta.index_page_offset.map(|x| x + shift);
+                meta.dictionary_page_offset = meta.dictionary_page_offset.map(|x| x + shift);
+            }
+        }
+        metadata.num_rows += row_group.num_rows;
+        metadata.row_groups.push(row_group);
+    }
+
+    // `write_metadata_sidecar` writes the footer preceded by the magic
+    let mut footer = vec![];
+    write_metadata_sidecar(&mut footer, &metadata)?;
+
+    file.write_all(&data[4..data_end])?;
+    file.write_all(&footer[4..])?;
+    Ok(size + (data_end - 4 + footer.len() - 4) as u64)
+}
@@ -6,7 +6,7 @@ use parquet2::{
     write::DynIter,
 };
 
-use crate::io::parquet::write::{slice_nested_leaf, utils};
+use crate::io::parquet::write::{get_max_length, slice_nested_leaf, slice_parquet_array, utils};
 use crate::{
     array::{Array, DictionaryArray, DictionaryKey},
     io::parquet::read::schema::is_nullable,
@@ -101,11 +101,13 @@ fn normalized_validity<K: DictionaryKey>(array: &DictionaryArray<K>) -> Option<B
     }
 }
 
+/// Serializes the keys of `array` to a data page. `statistics` are the ones of the dictionary
+/// values, whose null count is replaced by the one of the page.
 fn serialize_keys<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     type_: PrimitiveType,
     nested: &[Nested],
-    statistics: ParquetStatistics,
+    mut statistics: ParquetStatistics,
     options: WriteOptions,
 ) -> Result<Page> {
     let mut buffer = vec![];
@@ -115,6 +117,12 @@ fn serialize_keys<K: DictionaryKey>(
     let validity = normalized_validity(array);
     let (start, len) = slice_nested_leaf(nested);
 
+    let null_count = validity
+        .as_ref()
+        .map(|validity| validity.null_count_range(start, len))
+        .unwrap_or(0);
+    statistics.null_count = Some(null_count as i64);
+
     let (repetition_levels_byte_length, definition_levels_byte_length) = serialize_levels(
         validity.as_ref(),
         array.len(),
@@ -192,7 +200,8 @@ pub fn array_to_pages<K: DictionaryKey>(
 
                     let mut buffer = vec![];
                     utf8_encode_plain::<i32>(array, false, &mut buffer);
-                    let stats = utf8_build_statistics(array, type_.clone());
+                    let stats =
+                        utf8_build_statistics(array, type_.clone(), options.max_statistics_size);
                     (DictPage::new(buffer, array.len(), false), stats)
                 }
                 DataType::LargeUtf8 => {
@@ -200,7 +209,8 @@ pub fn array_to_pages<K: DictionaryKey>(
 
                     let mut buffer = vec![];
                     utf8_encode_plain::<i64>(array, false, &mut buffer);
-                    let stats = utf8_build_statistics(array, type_.clone());
+                    let stats =
+                        utf8_build_statistics(array, type_.clone(), options.max_statistics_size);
                     (DictPage::new(buffer, array.len(), false), stats)
                 }
                 DataType::Binary => {
@@ -235,10 +245,31 @@ pub fn array_to_pages<K: DictionaryKey>(
             };
             let dict_page = Page::Dict(dict_page);
 
-            // write DataPage pointing to DictPage
-            let data_page = serialize_keys(array, type_, nested, statistics, options)?;
+            // write DataPages pointing to DictPage, of at most `max_rows_per_page` rows each.
+            // They all share the min and max of the dictionary, but not its null count
+            let length = get_max_length(array, nested);
+            let rows_per_page = options.max_rows_per_page.unwrap_or(usize::MAX).max(1);
+            let data_pages = if length <= rows_per_page {
+                vec![serialize_keys(array, type_, nested, statistics, options)?]
+            } else {
+                (0..length)
+                    .step_by(rows_per_page)
+                    .map(|offset| {
+                        let length = rows_per_page.min(length - offset);
+                        let (sub_array, subnested) =
+                            slice_parquet_array(array, nested, offset, length);
+                        serialize_keys(
+                            sub_array.as_any().downcast_ref().unwrap(),
+                            type_.clone(),
+                            &subnested,
+                            statistics.clone(),
+                            options,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            };
 
-            let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
+            let iter = std::iter::once(dict_page).chain(data_pages).map(Ok);
             Ok(DynIter::new(Box::new(iter)))
         }
         _ => Err(Error::NotYetImplemented(
@@ -10,17 +10,18 @@ use crate::error::{Error, Result};
 
 use super::{schema::schema_to_metadata_key, to_parquet_schema, ThriftFileMetaData, WriteOptions};
 
-/// Attaches [`Schema`] to `key_value_metadata`
+/// Attaches [`Schema`] to `key_value_metadata`.
+///
+/// The result is deterministic: the arrow schema comes first, followed by `key_value_metadata`
+/// sorted by key, so that the same metadata always yields the same footer.
 pub fn add_arrow_schema(
     schema: &Schema,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.sort_by(|a, b| a.key.cmp(&b.key));
+    key_value_metadata.insert(0, schema_to_metadata_key(schema));
+    Some(key_value_metadata)
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -11,7 +11,13 @@
 //! * `DataType::Time32(TimeUnit::Second)`
 //!
 //! The use of these arrow types will result in no logical type being stored within a parquet file.
+//!
+//! # Writing strings as binary
+//! A [`Utf8Array`] whose field is declared as `DataType::Binary` (or `DataType::LargeBinary`)
+//! in the [`Schema`] is written as a `BYTE_ARRAY` without the UTF8 converted and logical
+//! types, i.e. as opaque binary, and is read back as a [`BinaryArray`].
 
+mod append;
 mod binary;
 mod boolean;
 mod dictionary;
@@ -58,9 +64,15 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_pagesize_limit: Option<usize>,
+    /// The maximum number of rows of a page, regardless of its size. Unlimited if None.
+    pub max_rows_per_page: Option<usize>,
+    /// The maximum size in bytes of the minimum and maximum statistics of utf8 columns, which
+    /// are truncated to it. Unlimited if None; the minimum and maximum are not written if zero.
+    pub max_statistics_size: Option<usize>,
 }
 
 use crate::compute::aggregate::estimated_bytes_size;
+pub use append::append_row_groups;
 pub use file::FileWriter;
 pub use row_group::{row_group_iter, RowGroupIterator};
 pub use schema::to_parquet_type;
@@ -131,6 +143,10 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
+            | (
+                Encoding::DeltaByteArray,
+                DataType::Utf8 | DataType::LargeUtf8
+            )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
             | (
@@ -260,6 +276,9 @@ pub fn array_to_pages(
                 let bytes_per_row =
                     ((array_byte_size as f64) / ((array.len() + 1) as f64)) as usize;
                 let rows_per_page = (page_size / (bytes_per_row + 1)).max(1);
+                let rows_per_page = options
+                    .max_rows_per_page
+                    .map_or(rows_per_page, |max| rows_per_page.min(max.max(1)));
 
                 let length = get_max_length(array, nested);
                 let vs: Vec<Result<Page>> = (0..length)
@@ -36,6 +36,8 @@ use super::{Encoding, SchemaDescriptor, WriteOptions};
 ///     compression: CompressionOptions::Uncompressed,
 ///     version: Version::V2,
 ///     data_pagesize_limit: None,
+///     max_rows_per_page: None,
+///     max_statistics_size: None,
 /// };
 ///
 /// let mut buffer = vec![];
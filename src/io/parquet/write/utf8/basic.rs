@@ -1,5 +1,5 @@
 use parquet2::{
-    encoding::Encoding,
+    encoding::{delta_bitpacked, Encoding},
     page::DataPage,
     schema::types::PrimitiveType,
     statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
@@ -39,6 +39,54 @@ pub(crate) fn encode_plain<O: Offset>(
     }
 }
 
+/// Encodes the non-null values of `array` as `DELTA_BYTE_ARRAY` (incremental encoding):
+/// the lengths of the prefix shared with the previous value, the lengths of the remaining
+/// suffixes, both delta-encoded, followed by the suffixes.
+fn encode_delta_byte_array<O: Offset>(
+    array: &Utf8Array<O>,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) {
+    let values = if is_optional {
+        array
+            .iter()
+            .flatten()
+            .map(|x| x.as_bytes())
+            .collect::<Vec<_>>()
+    } else {
+        array
+            .values_iter()
+            .map(|x| x.as_bytes())
+            .collect::<Vec<_>>()
+    };
+
+    let mut previous: &[u8] = &[];
+    let prefixes = values
+        .iter()
+        .map(|value| {
+            let prefix = previous
+                .iter()
+                .zip(value.iter())
+                .take_while(|(a, b)| a == b)
+                .count();
+            previous = *value;
+            prefix
+        })
+        .collect::<Vec<_>>();
+
+    delta_bitpacked::encode(prefixes.iter().map(|x| *x as i64), buffer);
+    delta_bitpacked::encode(
+        values
+            .iter()
+            .zip(prefixes.iter())
+            .map(|(value, prefix)| (value.len() - prefix) as i64),
+        buffer,
+    );
+    for (value, prefix) in values.iter().zip(prefixes.iter()) {
+        buffer.extend_from_slice(&value[*prefix..]);
+    }
+}
+
 pub fn array_to_page<O: Offset>(
     array: &Utf8Array<O>,
     options: WriteOptions,
@@ -68,6 +116,7 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, is_optional, &mut buffer),
         _ => {
             return Err(Error::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -78,7 +127,11 @@ pub fn array_to_page<O: Offset>(
     }
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, type_.clone()))
+        Some(build_statistics(
+            array,
+            type_.clone(),
+            options.max_statistics_size,
+        ))
     } else {
         None
     };
@@ -97,26 +150,186 @@ pub fn array_to_page<O: Offset>(
     )
 }
 
+/// Builds the statistics of `array`.
+///
+/// The minimum and maximum are found by scanning `array`: arrays carry no sortedness and the
+/// writer does not write sorting columns, so there is no hint to take them from its endpoints.
+///
+/// When `max_statistics_size` is set, the minimum and maximum are truncated to at most that
+/// many bytes (see [`truncate_down`] and [`truncate_up`]), and are not written if it is zero.
 pub(crate) fn build_statistics<O: Offset>(
     array: &Utf8Array<O>,
     primitive_type: PrimitiveType,
+    max_statistics_size: Option<usize>,
 ) -> ParquetStatistics {
+    let min_value = array
+        .iter()
+        .flatten()
+        .min_by(|x, y| ord_binary(x.as_bytes(), y.as_bytes()));
+    let max_value = array
+        .iter()
+        .flatten()
+        .max_by(|x, y| ord_binary(x.as_bytes(), y.as_bytes()));
+
+    let (min_value, max_value) = match max_statistics_size {
+        None => (
+            min_value.map(|x| x.as_bytes().to_vec()),
+            max_value.map(|x| x.as_bytes().to_vec()),
+        ),
+        Some(0) => (None, None),
+        Some(size) => (
+            min_value.map(|x| truncate_down(x, size).as_bytes().to_vec()),
+            max_value
+                .and_then(|x| truncate_up(x, size))
+                .map(String::into_bytes),
+        ),
+    };
+
     let statistics = &BinaryStatistics {
         primitive_type,
         null_count: Some(array.null_count() as i64),
         distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .map(|x| x.as_bytes())
-            .max_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-        min_value: array
-            .iter()
-            .flatten()
-            .map(|x| x.as_bytes())
-            .min_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
+        max_value,
+        min_value,
     } as &dyn Statistics;
     serialize_statistics(statistics)
 }
+
+/// Returns the longest prefix of `value` of at most `size` bytes that ends at a char boundary,
+/// which is a lower bound of `value`.
+fn truncate_down(value: &str, size: usize) -> &str {
+    if value.len() <= size {
+        return value;
+    }
+    let mut end = size;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    &value[..end]
+}
+
+/// Returns an upper bound of `value` of at most `size` bytes: `value` itself if it is not longer
+/// than `size` and otherwise [`truncate_down`] with its last char incremented. Chars that cannot
+/// be incremented (`char::MAX`) or whose increment is wider than the char it replaces and no
+/// longer fits in `size` are dropped, and the previous char is incremented instead.
+/// Returns `None` when no such upper bound exists.
+fn truncate_up(value: &str, size: usize) -> Option<String> {
+    if value.len() <= size {
+        return Some(value.to_string());
+    }
+    let mut prefix = truncate_down(value, size).to_string();
+    while let Some(last) = prefix.pop() {
+        match next_char(last) {
+            Some(next) if prefix.len() + next.len_utf8() <= size => {
+                prefix.push(next);
+                return Some(prefix);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the char after `c`, skipping the surrogate range, if any.
+fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        '\u{10FFFF}' => None,
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::schema::types::PhysicalType;
+
+    use super::*;
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncate_down("abc", 3), "abc");
+        assert_eq!(truncate_down("abcd", 3), "abc");
+        // "é" is 2 bytes long: it is either kept or dropped
+        assert_eq!(truncate_down("aéb", 2), "a");
+        assert_eq!(truncate_down("aéb", 3), "aé");
+
+        assert_eq!(truncate_up("abc", 3), Some("abc".to_string()));
+        assert_eq!(truncate_up("abcd", 3), Some("abd".to_string()));
+        assert_eq!(truncate_up("aéb", 2), Some("b".to_string()));
+        assert_eq!(truncate_up("aéb", 3), Some("aê".to_string()));
+        assert_eq!(truncate_up("a\u{D7FF}b", 4), Some("a\u{E000}".to_string()));
+        assert_eq!(truncate_up("éb", 1), None);
+    }
+
+    #[test]
+    fn truncate_up_max_char() {
+        // "\u{10FFFF}" is 4 bytes long
+        assert_eq!(truncate_up("a\u{10FFFF}b", 5), Some("b".to_string()));
+        assert_eq!(
+            truncate_up("ab\u{10FFFF}\u{10FFFF}c", 10),
+            Some("ac".to_string())
+        );
+        assert_eq!(truncate_up("\u{10FFFF}\u{10FFFF}b", 8), None);
+        assert_eq!(
+            truncate_up("\u{10FFFF}\u{10FFFF}b", 9),
+            Some("\u{10FFFF}\u{10FFFF}b".to_string())
+        );
+        assert_eq!(truncate_up("\u{10FFFF}", 4), Some("\u{10FFFF}".to_string()));
+        for value in ["a\u{10FFFF}b", "ab\u{10FFFF}\u{10FFFF}c"] {
+            for size in 1..value.len() {
+                if let Some(upper) = truncate_up(value, size) {
+                    assert!(upper.as_str() > value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn truncate_up_wider_char() {
+        // "\u{7F}" (1 byte) is incremented to "\u{80}" (2 bytes), which does not fit
+        assert_eq!(truncate_up("a\u{7F}b", 2), Some("b".to_string()));
+        assert_eq!(truncate_up("a\u{7F}é", 3), Some("a\u{80}".to_string()));
+        assert_eq!(truncate_up("\u{7F}b", 1), None);
+        // "\u{7FF}" (2 bytes) is incremented to "\u{800}" (3 bytes)
+        assert_eq!(
+            truncate_up("\u{7FF}\u{7FF}b", 4),
+            Some("\u{800}".to_string())
+        );
+        // "\u{FFFF}" (3 bytes) is incremented to "\u{10000}" (4 bytes)
+        assert_eq!(truncate_up("a\u{FFFF}b", 4), Some("b".to_string()));
+        for value in ["a\u{7F}b", "\u{7FF}\u{7FF}b", "a\u{FFFF}\u{7F}b"] {
+            for size in 1..value.len() {
+                if let Some(upper) = truncate_up(value, size) {
+                    assert!(upper.len() <= size);
+                    assert!(upper.as_str() > value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_char_statistics() {
+        let type_ = PrimitiveType::from_physical("a".to_string(), PhysicalType::ByteArray);
+        let array = Utf8Array::<i32>::from_slice(["a", "\u{10FFFF}\u{10FFFF}"]);
+
+        let statistics = build_statistics(&array, type_, Some(4));
+        assert_eq!(statistics.min_value, Some(b"a".to_vec()));
+        assert_eq!(statistics.max_value, None);
+    }
+
+    #[test]
+    fn truncated_statistics() {
+        let type_ = PrimitiveType::from_physical("a".to_string(), PhysicalType::ByteArray);
+        let array = Utf8Array::<i32>::from_slice(["aaaa", "zzzz"]);
+
+        let statistics = build_statistics(&array, type_.clone(), Some(2));
+        assert_eq!(statistics.min_value, Some(b"aa".to_vec()));
+        assert_eq!(statistics.max_value, Some(b"z{".to_vec()));
+        assert_eq!(statistics.null_count, Some(0));
+
+        let statistics = build_statistics(&array, type_, Some(0));
+        assert_eq!(statistics.min_value, None);
+        assert_eq!(statistics.max_value, None);
+        assert_eq!(statistics.null_count, Some(0));
+    }
+}
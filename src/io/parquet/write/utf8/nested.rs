@@ -34,7 +34,11 @@ where
     encode_plain(&array, is_optional, &mut buffer);
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(&array, type_.clone()))
+        Some(build_statistics(
+            &array,
+            type_.clone(),
+            options.max_statistics_size,
+        ))
     } else {
         None
     };
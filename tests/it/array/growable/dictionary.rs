@@ -62,3 +62,50 @@ fn test_multi() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn single_common_value() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a"]).boxed();
+    let array1 =
+        DictionaryArray::try_from_keys(PrimitiveArray::from(&[Some(0), None]), values.clone())?;
+    let array2 = DictionaryArray::try_from_keys(PrimitiveArray::from_vec(vec![0, 0]), values)?;
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+
+    growable.extend(0, 0, 2);
+    growable.extend(1, 0, 2);
+
+    let result: DictionaryArray<i32> = growable.into();
+
+    assert_eq!(result.values().len(), 1);
+    assert_eq!(
+        result.keys(),
+        &PrimitiveArray::from(&[Some(0), None, Some(0), Some(0)])
+    );
+    Ok(())
+}
+
+#[test]
+fn single_different_values() -> Result<()> {
+    let array1 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![0]),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+    )?;
+    let array2 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![0]),
+        Utf8Array::<i32>::from_slice(["b"]).boxed(),
+    )?;
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2], false, 0);
+
+    growable.extend(0, 0, 1);
+    growable.extend(1, 0, 1);
+
+    let result: DictionaryArray<i32> = growable.into();
+
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+    Ok(())
+}
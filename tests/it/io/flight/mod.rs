@@ -1,10 +1,15 @@
-use arrow2::array::Array;
+use arrow2::array::{Array, DictionaryArray, Int32Array, PrimitiveArray, UnionArray, Utf8Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{DataType, Field, IntegerType, Metadata, Schema, UnionMode};
 use arrow2::error::Error;
+use arrow2::io::ipc::read::Dictionaries;
+use arrow2::io::ipc::{IpcField, IpcSchema};
 
 use arrow2::io::flight::*;
-use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+use arrow2::io::ipc::write::{default_ipc_fields, Compression, WriteOptions};
+use arrow_format::flight::data::FlightData;
+use arrow_format::ipc::planus::{Builder, ReadAsRoot};
+use arrow_format::ipc::{Buffer, Message, MessageHeader, MessageRef, MetadataVersion};
 
 use super::ipc::read_gzip_json;
 
@@ -14,9 +19,10 @@ fn round_trip(schema: Schema, chunk: Chunk<Box<dyn Array>>) -> Result<(), Error>
     let (result, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
     assert_eq!(schema, result);
 
-    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions { compression: None })?;
+    let (dictionaries, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
 
-    let result = deserialize_batch(&batch, &result.fields, &ipc_schema, &Default::default())?;
+    let dictionaries = build_dictionaries(&dictionaries, &result.fields, &ipc_schema)?;
+    let result = deserialize_batch(&batch, &result.fields, &ipc_schema, &dictionaries)?;
     assert_eq!(result, chunk);
     Ok(())
 }
@@ -30,3 +36,1142 @@ fn generated_nested_dictionary() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn generated_round_trip() -> Result<(), Error> {
+    for file in [
+        "generated_primitive",
+        "generated_primitive_large_offsets",
+        "generated_primitive_zerolength",
+        "generated_datetime",
+        "generated_decimal",
+        "generated_interval",
+        "generated_null",
+        "generated_null_trivial",
+        "generated_nested",
+        "generated_nested_large_offsets",
+        "generated_map",
+        "generated_dictionary",
+        "generated_dictionary_unsigned",
+        "generated_nested_dictionary",
+        "generated_union",
+        "generated_custom_metadata",
+    ] {
+        let (schema, _, batches) = read_gzip_json("1.0.0-littleendian", file).unwrap();
+        for chunk in batches {
+            round_trip(schema.clone(), chunk).map_err(|e| {
+                Error::InvalidArgumentError(format!("{file} failed to round trip: {e}"))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lenient_keeps_valid_columns() -> Result<(), Error> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Int32, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        Utf8Array::<i32>::from_slice(["aa", "bb", "cc"]).boxed(),
+        Int32Array::from_slice([4, 5, 6]).boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let (_, mut batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+
+    // corrupt the values of column "b" so that they are no longer valid utf8
+    let start = batch
+        .data_body
+        .windows(6)
+        .position(|x| x == b"aabbcc")
+        .unwrap();
+    batch.data_body[start] = 0xff;
+
+    assert!(deserialize_batch(&batch, &schema.fields, &ipc_schema, &Default::default()).is_err());
+
+    let (result, errors) =
+        deserialize_batch_lenient(&batch, &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(
+        result,
+        Chunk::new(vec![chunk.arrays()[0].clone(), chunk.arrays()[2].clone()])
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+    Ok(())
+}
+
+#[test]
+fn lenient_skips_mismatched_columns() -> Result<(), Error> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        Int32Array::from_slice([4, 5, 6]).boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+
+    // declare 2 rows for column "b" while the message declares 3
+    let mut message =
+        Message::try_from(MessageRef::read_as_root(&batch.data_header).unwrap()).unwrap();
+    match message.header.as_mut() {
+        Some(MessageHeader::RecordBatch(batch)) => batch.nodes.as_mut().unwrap()[1].length = 2,
+        other => panic!("expected a record batch, got {other:?}"),
+    }
+    let shorter = FlightData {
+        data_header: Builder::new().finish(&message, None).to_vec(),
+        data_body: batch.data_body.clone(),
+        ..Default::default()
+    };
+    let (result, errors) =
+        deserialize_batch_lenient(&shorter, &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(result, Chunk::new(vec![chunk.arrays()[0].clone()]));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+
+    // the message has more columns than the fields: the extra column is reported
+    let fewer_fields = &schema.fields[..1];
+    let (result, mut errors) =
+        deserialize_batch_lenient(&batch, fewer_fields, &ipc_schema, &Default::default())?;
+    assert_eq!(result, Chunk::new(vec![chunk.arrays()[0].clone()]));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+    let error = flight_error(errors.pop().unwrap().1);
+    assert_eq!(error.stage, FlightStage::Batch);
+    assert!(matches!(error.source, Error::OutOfSpec(_)));
+    Ok(())
+}
+
+fn dictionary_chunk(values: &[&str], keys: Vec<i32>) -> Chunk<Box<dyn Array>> {
+    let values = Utf8Array::<i32>::from_slice(values).boxed();
+    let array = DictionaryArray::try_from_keys(PrimitiveArray::from_vec(keys), values).unwrap();
+    Chunk::new(vec![array.boxed()])
+}
+
+#[test]
+fn dictionary_replacement() -> Result<(), Error> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let mut dictionaries = Dictionaries::with_capacity(1);
+    let capacity = dictionaries.capacity();
+
+    for chunk in [
+        dictionary_chunk(&["a", "b"], vec![0, 1, 1]),
+        dictionary_chunk(&["c"], vec![0, 0]),
+    ] {
+        let (dicts, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+        assert_eq!(dicts.len(), 1);
+        for dict in &dicts {
+            deserialize_message(dict, &schema.fields, &ipc_schema, &mut dictionaries)?;
+        }
+        let result = deserialize_message(&batch, &schema.fields, &ipc_schema, &mut dictionaries)?;
+        assert_eq!(result, Some(chunk));
+    }
+
+    // the replacement took the entry of the previous dictionary: the map did not grow
+    assert_eq!(dictionaries.len(), 1);
+    assert_eq!(dictionaries.capacity(), capacity);
+    Ok(())
+}
+
+#[test]
+fn projected_batch() -> Result<(), Error> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Int32, true),
+        Field::new("d", DataType::Utf8, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        Int32Array::from_slice([4, 5, 6]).boxed(),
+        Utf8Array::<i32>::from_slice(["d", "e", "f"]).boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let projection = [3, 1];
+
+    let (_, batch) =
+        serialize_batch_projected(&chunk, &fields, &WriteOptions::new(None), &projection)?;
+
+    let projected_schema = Schema::from(vec![schema.fields[3].clone(), schema.fields[1].clone()]);
+    let projected_fields = default_ipc_fields(&projected_schema.fields);
+    let (_, ipc_schema) = deserialize_schemas(
+        &serialize_schema(&projected_schema, Some(&projected_fields)).data_header,
+    )?;
+
+    let result = deserialize_batch(
+        &batch,
+        &projected_schema.fields,
+        &ipc_schema,
+        &Default::default(),
+    )?;
+    assert_eq!(
+        result,
+        Chunk::new(vec![chunk.arrays()[3].clone(), chunk.arrays()[1].clone()])
+    );
+    Ok(())
+}
+
+#[test]
+fn projected_batch_out_of_bounds() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2, 3]).boxed()]);
+    let fields = default_ipc_fields(&schema.fields);
+
+    let result = serialize_batch_projected(&chunk, &fields, &WriteOptions::new(None), &[1]);
+    assert!(result.is_err());
+}
+
+fn two_dictionaries() -> (Schema, Chunk<Box<dyn Array>>) {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![
+        Field::new("a", data_type.clone(), true),
+        Field::new("b", data_type, true),
+    ]);
+    let a = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![0, 1, 0]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )
+    .unwrap();
+    let b = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![1, 1, 0]),
+        Utf8Array::<i32>::from_slice(["c", "d"]).boxed(),
+    )
+    .unwrap();
+    (schema, Chunk::new(vec![a.boxed(), b.boxed()]))
+}
+
+#[test]
+fn build_dictionaries_from_messages() -> Result<(), Error> {
+    let (schema, chunk) = two_dictionaries();
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let (dicts, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+    assert_eq!(dicts.len(), 2);
+
+    let dictionaries = build_dictionaries(&dicts, &schema.fields, &ipc_schema)?;
+    assert_eq!(dictionaries.len(), 2);
+
+    let result = deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries)?;
+    assert_eq!(result, chunk);
+    Ok(())
+}
+
+#[test]
+fn build_dictionaries_duplicated_id() -> Result<(), Error> {
+    let (schema, chunk) = two_dictionaries();
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let (dicts, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+
+    let duplicated = [dicts[0].clone(), dicts[0].clone()];
+    assert!(build_dictionaries(&duplicated, &schema.fields, &ipc_schema).is_err());
+
+    // a record batch is not a dictionary message
+    assert!(build_dictionaries(&[batch], &schema.fields, &ipc_schema).is_err());
+    Ok(())
+}
+
+#[test]
+fn stream_reader_yields_latest_schema() -> Result<(), Error> {
+    let options = WriteOptions::new(None);
+    let schema1 = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunk1 = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let schema2 = Schema::from(vec![Field::new("b", DataType::Utf8, true)]);
+    let chunk2 = Chunk::new(vec![Utf8Array::<i32>::from_slice(["a", "b"]).boxed()]);
+
+    let mut reader = FlightStreamReader::new();
+    for (schema, chunk) in [(schema1, chunk1), (schema2, chunk2)] {
+        let fields = default_ipc_fields(&schema.fields);
+        assert!(reader
+            .next_with_schema(&serialize_schema(&schema, Some(&fields)))?
+            .is_none());
+        assert_eq!(reader.schema().map(|x| x.as_ref()), Some(&schema));
+
+        let (_, batch) = serialize_batch(&chunk, &fields, &options)?;
+        let (result, result_schema) = reader.next_with_schema(&batch)?.unwrap();
+        assert_eq!(result, chunk);
+        assert_eq!(result_schema.as_ref(), &schema);
+    }
+    Ok(())
+}
+
+#[test]
+fn stream_reader_requires_schema() {
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let fields = default_ipc_fields(&[Field::new("a", DataType::Int32, true)]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None)).unwrap();
+
+    assert!(FlightStreamReader::new().next_with_schema(&batch).is_err());
+}
+
+#[test]
+fn flight_to_ipc_file() -> Result<(), Error> {
+    let options = WriteOptions::new(None);
+    let chunk1 = dictionary_chunk(&["a", "b"], vec![0, 1, 0]);
+    let chunk2 = dictionary_chunk(&["a", "b"], vec![1, 1]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk1.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+
+    let mut writer = FlightToIpcFileWriter::new(std::io::Cursor::new(vec![]), options);
+    writer.write(serialize_schema(&schema, Some(&fields)))?;
+    for chunk in [&chunk1, &chunk2] {
+        // the unchanged dictionary is re-sent with every batch
+        let (dictionaries, batch) = serialize_batch(chunk, &fields, &options)?;
+        for dictionary in dictionaries {
+            writer.write(dictionary)?;
+        }
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    let data = writer.into_inner().into_inner();
+
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let reader = arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None);
+    let chunks = reader.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(chunks, vec![chunk1, chunk2]);
+    Ok(())
+}
+
+#[test]
+fn flight_to_ipc_file_dictionary_replacement() -> Result<(), Error> {
+    let options = WriteOptions::new(None);
+    let chunk1 = dictionary_chunk(&["a", "b"], vec![0, 1, 0]);
+    let chunk2 = dictionary_chunk(&["c"], vec![0]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk1.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+
+    let mut writer = FlightToIpcFileWriter::new(vec![], options);
+    writer.write(serialize_schema(&schema, Some(&fields)))?;
+    let (mut dictionaries, batch) = serialize_batch(&chunk1, &fields, &options)?;
+    writer.write(dictionaries.pop().unwrap())?;
+    writer.write(batch)?;
+
+    let (mut dictionaries, _) = serialize_batch(&chunk2, &fields, &options)?;
+    assert!(matches!(
+        writer.write(dictionaries.pop().unwrap()),
+        Err(Error::InvalidArgumentError(_))
+    ));
+    Ok(())
+}
+
+struct FailingWriter;
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::Other.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn flight_to_ipc_file_writer_error() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &Default::default()).unwrap();
+
+    let mut writer = FlightToIpcFileWriter::new(FailingWriter, Default::default());
+    assert!(matches!(
+        writer.write(serialize_schema(&schema, Some(&fields))),
+        Err(Error::Io(_))
+    ));
+    // the error is recoverable: further calls error instead of panicking
+    // and retrying the schema does not start the file a second time
+    assert!(matches!(
+        writer.write(serialize_schema(&schema, Some(&fields))),
+        Err(Error::InvalidArgumentError(_))
+    ));
+    assert!(writer.write(batch).is_err());
+    assert!(writer.finish().is_err());
+    let _ = writer.into_inner();
+}
+
+#[test]
+fn flight_to_ipc_file_compression_mismatch() -> Result<(), Error> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+
+    let options = WriteOptions::new(Some(Compression::LZ4));
+    let mut writer = FlightToIpcFileWriter::new(vec![], options);
+    writer.write(serialize_schema(&schema, Some(&fields)))?;
+    // an uncompressed batch can't be written to a file declared as compressed
+    assert!(matches!(
+        writer.write(batch),
+        Err(Error::InvalidArgumentError(_))
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn flight_to_ipc_file_compressed() -> Result<(), Error> {
+    let options = WriteOptions::new(Some(Compression::LZ4));
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &options)?;
+
+    let mut writer = FlightToIpcFileWriter::new(vec![], options);
+    writer.write(serialize_schema(&schema, Some(&fields)))?;
+    writer.write(batch)?;
+    writer.finish()?;
+
+    let mut reader = std::io::Cursor::new(writer.into_inner());
+    let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)?;
+    let reader = arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None);
+    let chunks = reader.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn end_of_stream_message() -> Result<(), Error> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::new(None))?;
+
+    let eos = end_of_stream();
+    assert!(is_end_of_stream(&eos));
+    assert!(!is_end_of_stream(&batch));
+
+    // a metadata-only message (e.g. of `DoPut`) does not end the stream
+    let metadata = FlightData {
+        app_metadata: b"progress".to_vec(),
+        ..Default::default()
+    };
+    assert!(!is_end_of_stream(&metadata));
+    let metadata = FlightData {
+        app_metadata: b"progress".to_vec(),
+        ..end_of_stream()
+    };
+    assert!(!is_end_of_stream(&metadata));
+
+    let mut dictionaries = Dictionaries::default();
+    // an end-of-stream message is not a dictionary message: it is only deserialized by
+    // `deserialize_message_typed`
+    let result = deserialize_message(&eos, &schema.fields, &ipc_schema, &mut dictionaries);
+    assert!(matches!(result, Err(Error::OutOfSpec(_))));
+    let result = deserialize_message_typed(&eos, &schema.fields, &ipc_schema, &mut dictionaries)?;
+    assert_eq!(result, FlightMessage::EndOfStream);
+    Ok(())
+}
+
+#[test]
+fn merge_dictionaries_disjoint() -> Result<(), Error> {
+    let a = Utf8Array::<i32>::from_slice(["a"]).boxed();
+    let b = Utf8Array::<i32>::from_slice(["b"]).boxed();
+    let mut map1 = Dictionaries::default();
+    map1.insert(0, a.clone());
+    let mut map2 = Dictionaries::default();
+    map2.insert(1, b.clone());
+    // the same values for the same id are not a conflict
+    map2.insert(0, a.clone());
+
+    let merged = merge_dictionaries(vec![map1, map2])?;
+    assert_eq!(merged.len(), 2);
+    assert_eq!(merged.get(&0), Some(&a));
+    assert_eq!(merged.get(&1), Some(&b));
+    Ok(())
+}
+
+#[test]
+fn merge_dictionaries_conflict() {
+    let mut map1 = Dictionaries::default();
+    map1.insert(0, Utf8Array::<i32>::from_slice(["a"]).boxed());
+    let mut map2 = Dictionaries::default();
+    map2.insert(0, Utf8Array::<i32>::from_slice(["b"]).boxed());
+
+    assert!(merge_dictionaries(vec![map1, map2]).is_err());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+fn batch_compression_info() -> Result<(), Error> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2, 3]).boxed()]);
+
+    let options = WriteOptions::new(Some(Compression::LZ4));
+    let (_, batch) = serialize_batch(&chunk, &fields, &options)?;
+    let (result, compression) =
+        deserialize_batch_with_info(&batch, &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(result, chunk);
+    assert_eq!(compression, Some(Compression::LZ4));
+
+    let options = WriteOptions::default();
+    let (_, batch) = serialize_batch(&chunk, &fields, &options)?;
+    let (_, compression) =
+        deserialize_batch_with_info(&batch, &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(compression, None);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // LZ4 and ZSTD use foreign calls that miri does not support
+fn compressed_round_trip() -> Result<(), Error> {
+    let (dict_schema, dict_chunk) = two_dictionaries();
+    let mut schema_fields = dict_schema.fields;
+    schema_fields.push(Field::new("c", DataType::Int32, true));
+    schema_fields.push(Field::new("d", DataType::Utf8, true));
+    let schema = Schema::from(schema_fields);
+    let mut arrays = dict_chunk.into_arrays();
+    arrays.push(Int32Array::from([Some(1), None, Some(3)]).boxed());
+    arrays.push(Utf8Array::<i32>::from([Some("aa"), None, Some("c")]).boxed());
+    let chunk = Chunk::new(arrays);
+
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let options = WriteOptions::new(Some(compression));
+        let (dicts, batch) = serialize_batch(&chunk, &fields, &options)?;
+        let mut dictionaries = build_dictionaries(&dicts, &schema.fields, &ipc_schema)?;
+
+        let result = deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries)?;
+        assert_eq!(result, chunk);
+
+        let result = deserialize_message(&batch, &schema.fields, &ipc_schema, &mut dictionaries)?;
+        assert_eq!(result, Some(chunk.clone()));
+    }
+    Ok(())
+}
+
+#[test]
+fn typed_message_loop() -> Result<(), Error> {
+    let chunk = dictionary_chunk(&["a", "b"], vec![0, 1, 1]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (dicts, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+
+    let mut messages = vec![serialize_schema(&schema, Some(&fields))];
+    messages.extend(dicts);
+    messages.push(batch);
+    messages.push(end_of_stream());
+
+    let mut current_schema = Schema::default();
+    let mut ipc_schema = IpcSchema {
+        fields: vec![],
+        is_little_endian: true,
+    };
+    let mut dictionaries = Dictionaries::default();
+    let mut chunks = vec![];
+    for data in &messages {
+        match deserialize_message_typed(
+            data,
+            &current_schema.fields,
+            &ipc_schema,
+            &mut dictionaries,
+        )? {
+            FlightMessage::Schema(schema, ipc) => {
+                current_schema = schema;
+                ipc_schema = ipc;
+            }
+            FlightMessage::Chunk(chunk) => chunks.push(chunk),
+            FlightMessage::Dictionary => {}
+            FlightMessage::EndOfStream => break,
+        }
+    }
+    assert_eq!(current_schema, schema);
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn validate_chunk() {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    ]);
+    assert!(validate_chunk_against_schema(&chunk, &schema).is_ok());
+
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i64>::from_slice(["a", "b"]).boxed(),
+    ]);
+    assert!(matches!(
+        validate_chunk_against_schema(&chunk, &schema),
+        Err(Error::InvalidArgumentError(_))
+    ));
+
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    assert!(validate_chunk_against_schema(&chunk, &schema).is_err());
+}
+
+#[test]
+fn get_schema_consistent_with_do_get() -> Result<(), Error> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+
+    let (result, data) = serialize_schema_for_get_schema_and_do_get(&schema, Some(&fields));
+    check_schema_consistent(&result.schema, &data.data_header)?;
+    Ok(())
+}
+
+#[test]
+fn get_schema_inconsistent_with_do_get() {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let mut fields = default_ipc_fields(&schema.fields);
+
+    let result = serialize_schema_to_result(&schema, Some(&fields));
+    fields[0].dictionary_id = Some(1);
+    let data = serialize_schema(&schema, Some(&fields));
+    assert!(matches!(
+        check_schema_consistent(&result.schema, &data.data_header),
+        Err(Error::InvalidArgumentError(_))
+    ));
+}
+
+fn flight_error(error: Error) -> FlightError {
+    match error {
+        Error::External(_, error) => *error.downcast::<FlightError>().unwrap(),
+        error => panic!("unexpected error {error:?}"),
+    }
+}
+
+#[test]
+fn error_stages() -> Result<(), Error> {
+    let (schema, chunk) = two_dictionaries();
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let (dicts, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+    let dictionaries = build_dictionaries(&dicts, &schema.fields, &ipc_schema)?;
+
+    // a record batch message is not a schema
+    let error = deserialize_schemas(&batch.data_header).unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Schema));
+
+    // a dictionary message is not a record batch
+    let error =
+        deserialize_batch(&dicts[0], &schema.fields, &ipc_schema, &dictionaries).unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Batch));
+
+    // a record batch message is not a dictionary
+    let error = build_dictionaries(&[batch.clone()], &schema.fields, &ipc_schema).unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Dictionary));
+
+    // the record batch references dictionaries that were not read
+    let error =
+        deserialize_message_typed(&batch, &schema.fields, &ipc_schema, &mut Default::default())
+            .unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Batch));
+
+    // successful deserialization is unaffected
+    assert_eq!(
+        deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries)?,
+        chunk
+    );
+
+    // the underlying error is kept as the source
+    let error = deserialize_schemas(&[]).unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Schema));
+    let error = flight_error(error);
+    assert!(std::error::Error::source(&error).is_some());
+    assert!(matches!(error.source, Error::OutOfSpec(_)));
+
+    // a header that cannot be read
+    let data = FlightData {
+        data_header: vec![1, 2, 3],
+        ..Default::default()
+    };
+    let error =
+        deserialize_message_typed(&data, &schema.fields, &ipc_schema, &mut Default::default())
+            .unwrap_err();
+    assert_eq!(stage_of(&error), Some(FlightStage::Header));
+
+    // untagged errors are not flight errors
+    assert_eq!(stage_of(&Error::Overflow), None);
+    assert_eq!(stage_of(&Error::OutOfSpec("other".to_string())), None);
+    Ok(())
+}
+
+#[test]
+fn schema_and_batch() -> Result<(), Error> {
+    let (schema, chunk) = two_dictionaries();
+
+    let datas = serialize_schema_and_batch(&schema, None, &chunk, &WriteOptions::default())?;
+    assert_eq!(datas.len(), 4);
+
+    let mut reader = FlightStreamReader::new();
+    assert!(reader.next_with_schema(&datas[0])?.is_none());
+    assert_eq!(reader.schema().map(|x| x.as_ref()), Some(&schema));
+    assert!(reader.next_with_schema(&datas[1])?.is_none());
+    assert!(reader.next_with_schema(&datas[2])?.is_none());
+    let (result, _) = reader.next_with_schema(&datas[3])?.unwrap();
+    assert_eq!(result, chunk);
+    Ok(())
+}
+
+#[test]
+fn deserialize_projected() -> Result<(), Error> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Int32, true),
+        Field::new("d", DataType::Utf8, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("aa"), Some("b"), None]).boxed(),
+        Int32Array::from_slice([4, 5, 6]).boxed(),
+        Utf8Array::<i32>::from_slice(["d", "ee", "fff"]).boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+
+    let result = deserialize_batch_projected(
+        &batch,
+        &schema.fields,
+        &ipc_schema,
+        &Default::default(),
+        &[3, 0, 2],
+    )?;
+    assert_eq!(
+        result,
+        Chunk::new(vec![
+            chunk.arrays()[3].clone(),
+            chunk.arrays()[0].clone(),
+            chunk.arrays()[2].clone(),
+        ])
+    );
+
+    for projection in [&[4][..], &[1, 1]] {
+        assert!(matches!(
+            deserialize_batch_projected(
+                &batch,
+                &schema.fields,
+                &ipc_schema,
+                &Default::default(),
+                projection
+            ),
+            Err(Error::InvalidArgumentError(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn serializer_emits_changed_dictionaries() -> Result<(), Error> {
+    let chunk = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let mut serializer = FlightSerializer::new(WriteOptions::default());
+    let mut dictionaries = Dictionaries::default();
+    for (i, keys) in [vec![0, 1], vec![1, 1], vec![1, 0]].into_iter().enumerate() {
+        let chunk = dictionary_chunk(&["a", "b"], keys);
+        let (dicts, batch) = serializer.serialize_batch(&chunk, &fields)?;
+        assert_eq!(dicts.len(), usize::from(i == 0));
+        for dict in &dicts {
+            deserialize_dictionary(dict, &schema.fields, &ipc_schema, &mut dictionaries)?;
+        }
+        let result = deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries)?;
+        assert_eq!(result, chunk);
+    }
+
+    // a replaced dictionary is serialized again
+    let chunk = dictionary_chunk(&["c"], vec![0]);
+    let (dicts, _) = serializer.serialize_batch(&chunk, &fields)?;
+    assert_eq!(dicts.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn serializer_dictionary_policy_error() -> Result<(), Error> {
+    let chunk = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let fields =
+        default_ipc_fields(&[Field::new("a", chunk.arrays()[0].data_type().clone(), true)]);
+
+    let mut serializer = FlightSerializer::new(
+        WriteOptions::new(None).with_dictionary_policy(DictionaryPolicy::Error),
+    );
+    serializer.serialize_batch(&chunk, &fields)?;
+    serializer.serialize_batch(&chunk, &fields)?;
+    assert!(serializer
+        .serialize_batch(&dictionary_chunk(&["c"], vec![0]), &fields)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn serializer_dictionary_policy_delta() -> Result<(), Error> {
+    let chunk = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let fields =
+        default_ipc_fields(&[Field::new("a", chunk.arrays()[0].data_type().clone(), true)]);
+
+    let mut serializer = FlightSerializer::new(
+        WriteOptions::new(None).with_dictionary_policy(DictionaryPolicy::Delta),
+    );
+    serializer.serialize_batch(&chunk, &fields)?;
+    let (mut dicts, _) =
+        serializer.serialize_batch(&dictionary_chunk(&["a", "b", "c"], vec![2, 0]), &fields)?;
+    assert_eq!(dicts.len(), 1);
+    let dict = dicts.pop().unwrap();
+
+    let mut message =
+        Message::try_from(MessageRef::read_as_root(&dict.data_header).unwrap()).unwrap();
+    let batch = match message.header.take() {
+        Some(MessageHeader::DictionaryBatch(batch)) => batch,
+        other => panic!("expected a dictionary batch, got {other:?}"),
+    };
+    assert!(batch.is_delta);
+    assert_eq!(Some(batch.id), fields[0].dictionary_id);
+
+    // the delta contains only the appended values: read them as a record batch of the values
+    message.header = Some(MessageHeader::RecordBatch(batch.data.unwrap()));
+    let values = FlightData {
+        data_header: Builder::new().finish(&message, None).to_vec(),
+        data_body: dict.data_body,
+        ..Default::default()
+    };
+    let ipc_schema = IpcSchema {
+        fields: vec![IpcField {
+            fields: vec![],
+            dictionary_id: None,
+        }],
+        is_little_endian: cfg!(target_endian = "little"),
+    };
+    let values = deserialize_batch(
+        &values,
+        &[Field::new("", DataType::Utf8, false)],
+        &ipc_schema,
+        &Default::default(),
+    )?;
+    assert_eq!(
+        values,
+        Chunk::new(vec![Utf8Array::<i32>::from_slice(["c"]).boxed()])
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_dictionary_delta() -> Result<(), Error> {
+    let chunk1 = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let chunk2 = dictionary_chunk(&["a", "b", "c"], vec![2, 0]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk1.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+
+    let mut serializer = FlightSerializer::new(
+        WriteOptions::new(None).with_dictionary_policy(DictionaryPolicy::Delta),
+    );
+    let mut dictionaries = Dictionaries::default();
+    for chunk in [chunk1, chunk2] {
+        let (dicts, batch) = serializer.serialize_batch(&chunk, &fields)?;
+        for dict in &dicts {
+            deserialize_dictionary(dict, &schema.fields, &ipc_schema, &mut dictionaries)?;
+        }
+        assert_eq!(
+            deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries)?,
+            chunk
+        );
+    }
+    Ok(())
+}
+
+fn with_version(data: &FlightData, version: MetadataVersion) -> FlightData {
+    let message = MessageRef::read_as_root(&data.data_header).unwrap();
+    let mut message = Message::try_from(message).unwrap();
+    message.version = version;
+    FlightData {
+        data_header: Builder::new().finish(&message, None).to_vec(),
+        data_body: data.data_body.clone(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn message_metadata_version() -> Result<(), Error> {
+    let union_fields = vec![
+        Field::new("x", DataType::Int32, true),
+        Field::new("y", DataType::Utf8, true),
+    ];
+    let union_type = DataType::Union(union_fields, None, UnionMode::Sparse);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", union_type.clone(), true),
+    ]);
+    let union = UnionArray::new(
+        union_type,
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), None]).boxed(),
+        ],
+        None,
+    );
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("aa"), None, Some("c")]).boxed(),
+        union.boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, Some(&fields)).data_header)?;
+    let (_, v5) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+
+    // unions are preceded by an (empty) validity buffer up to V4 but not since V5
+    let mut message =
+        Message::try_from(MessageRef::read_as_root(&v5.data_header).unwrap()).unwrap();
+    message.version = MetadataVersion::V4;
+    match message.header.as_mut() {
+        Some(MessageHeader::RecordBatch(batch)) => batch.buffers.as_mut().unwrap().insert(
+            5,
+            Buffer {
+                offset: 0,
+                length: 0,
+            },
+        ),
+        other => panic!("expected a record batch, got {other:?}"),
+    }
+    let v4 = FlightData {
+        data_header: Builder::new().finish(&message, None).to_vec(),
+        data_body: v5.data_body.clone(),
+        ..Default::default()
+    };
+
+    for batch in [&v4, &v5] {
+        let result =
+            deserialize_message(batch, &schema.fields, &ipc_schema, &mut Default::default())?;
+        assert_eq!(result, Some(chunk.clone()));
+    }
+
+    // the version of the message, not V5, decides how the union is read
+    let mislabeled = with_version(&v5, MetadataVersion::V4);
+    let result = deserialize_message(
+        &mislabeled,
+        &schema.fields,
+        &ipc_schema,
+        &mut Default::default(),
+    );
+    assert!(!matches!(result, Ok(Some(result)) if result == chunk));
+    Ok(())
+}
+
+#[test]
+fn schema_result_round_trip() -> Result<(), Error> {
+    let (schema, _) = two_dictionaries();
+    let mut field_metadata = Metadata::new();
+    field_metadata.insert("field_key".to_string(), "field_value".to_string());
+    let mut schema_metadata = Metadata::new();
+    schema_metadata.insert("schema_key".to_string(), "schema_value".to_string());
+    let schema = Schema::from(
+        schema
+            .fields
+            .into_iter()
+            .map(|field| field.with_metadata(field_metadata.clone()))
+            .collect::<Vec<_>>(),
+    )
+    .with_metadata(schema_metadata);
+
+    let ipc_schema = IpcSchema {
+        fields: vec![
+            IpcField {
+                fields: vec![],
+                dictionary_id: Some(5),
+            },
+            IpcField {
+                fields: vec![],
+                dictionary_id: Some(7),
+            },
+        ],
+        is_little_endian: cfg!(target_endian = "little"),
+    };
+
+    let result = serialize_ipc_schema_to_result(&schema, &ipc_schema);
+    let (result_schema, result_ipc_schema) = deserialize_schema_result(&result)?;
+    assert_eq!(
+        result_schema.metadata.get("schema_key").unwrap(),
+        "schema_value"
+    );
+    for field in &result_schema.fields {
+        assert_eq!(field.metadata.get("field_key").unwrap(), "field_value");
+    }
+    assert_eq!(result_schema, schema);
+    assert_eq!(result_ipc_schema, ipc_schema);
+    Ok(())
+}
+
+#[test]
+fn batch_with_sizes() -> Result<(), Error> {
+    let (schema, chunk) = two_dictionaries();
+    let fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions::default();
+
+    let (dictionaries, (batch, size)) = serialize_batch_with_sizes(&chunk, &fields, &options)?;
+    let (expected_dictionaries, expected_batch) = serialize_batch(&chunk, &fields, &options)?;
+
+    assert_eq!(dictionaries.len(), 2);
+    for ((data, size), expected) in dictionaries.iter().zip(expected_dictionaries) {
+        assert_eq!(data, &expected);
+        assert_eq!(*size, expected.data_header.len() + expected.data_body.len());
+    }
+    assert_eq!(batch, expected_batch);
+    assert_eq!(
+        size,
+        expected_batch.data_header.len() + expected_batch.data_body.len()
+    );
+    Ok(())
+}
+
+#[test]
+fn batch_with_wrong_number_of_columns() -> Result<(), Error> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    ]);
+    let fields = default_ipc_fields(&schema.fields);
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+
+    let read_schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let read_fields = default_ipc_fields(&read_schema.fields);
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&read_schema, Some(&read_fields)).data_header)?;
+
+    let errors = [
+        deserialize_batch(
+            &batch,
+            &read_schema.fields,
+            &ipc_schema,
+            &Default::default(),
+        ),
+        deserialize_message(
+            &batch,
+            &read_schema.fields,
+            &ipc_schema,
+            &mut Default::default(),
+        )
+        .map(|chunk| chunk.unwrap()),
+    ];
+    for error in errors {
+        let error = flight_error(error.unwrap_err());
+        assert_eq!(error.stage, FlightStage::Batch);
+        match error.source {
+            Error::OutOfSpec(message) => assert_eq!(
+                message,
+                "The record batch message has 2 field nodes but the 1 fields declare 1"
+            ),
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn stream_decoder() -> Result<(), Error> {
+    let chunk1 = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let chunk2 = dictionary_chunk(&["a", "b"], vec![1, 1]);
+    let chunk3 = dictionary_chunk(&["c"], vec![0, 0]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk1.arrays()[0].data_type().clone(),
+        true,
+    )]);
+    let fields = default_ipc_fields(&schema.fields);
+    let schema_data = serialize_schema(&schema, Some(&fields));
+    let (_, ipc_schema) = deserialize_schemas(&schema_data.data_header)?;
+
+    let mut serializer = FlightSerializer::new(WriteOptions::default());
+    let mut datas = vec![];
+    for chunk in [&chunk1, &chunk2, &chunk3] {
+        let (dictionaries, batch) = serializer.serialize_batch(chunk, &fields)?;
+        datas.extend(dictionaries);
+        datas.push(batch);
+    }
+    // dictionary, batch, batch, dictionary, batch
+    assert_eq!(datas.len(), 5);
+
+    let mut decoder = FlightStreamDecoder::with_schema(schema.clone(), ipc_schema);
+    let result = datas
+        .into_iter()
+        .map(|data| decoder.decode(data))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec![chunk1, chunk2, chunk3.clone()]);
+
+    // a schema message is surfaced and replaces the schema
+    let message = decoder.decode_message(&schema_data)?;
+    assert!(matches!(message, FlightMessage::Schema(..)));
+    assert_eq!(
+        decoder.schema().map(|schema| schema.as_ref()),
+        Some(&schema)
+    );
+    let (dictionaries, batch) = serialize_batch(&chunk3, &fields, &WriteOptions::default())?;
+    assert!(decoder.decode(batch.clone()).is_err());
+    for data in dictionaries {
+        assert!(decoder.decode(data)?.is_none());
+    }
+    assert_eq!(decoder.decode(batch)?, Some(chunk3));
+    Ok(())
+}
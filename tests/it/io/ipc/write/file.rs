@@ -17,7 +17,7 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> Result<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions::new(compression);
     let mut writer = FileWriter::try_new(result, schema, ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
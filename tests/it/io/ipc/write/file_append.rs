@@ -22,7 +22,7 @@ fn basic() -> Result<()> {
     // read the file to append
     let mut file = std::io::Cursor::new(result);
     let metadata = read::read_file_metadata(&mut file)?;
-    let mut writer = FileWriter::try_from_file(file, metadata, WriteOptions { compression: None })?;
+    let mut writer = FileWriter::try_from_file(file, metadata, WriteOptions::new(None))?;
 
     // write a new column
     writer.write(&columns, None)?;
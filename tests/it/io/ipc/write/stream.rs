@@ -1,12 +1,12 @@
 use std::io::Cursor;
 
-use arrow2::array::Array;
+use arrow2::array::{Array, DictionaryArray, PrimitiveArray, Utf8Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::write::{DictionaryPolicy, StreamWriter, WriteOptions};
 use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_arrow_stream;
@@ -19,7 +19,7 @@ fn write_(
 ) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
+    let options = WriteOptions::new(None);
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -146,3 +146,72 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+fn write_dictionaries(policy: DictionaryPolicy) -> Result<Vec<u8>> {
+    let chunk1 = dictionary_chunk(&["a", "b"], vec![0, 1]);
+    let chunk2 = dictionary_chunk(&["a", "b", "c"], vec![2, 0]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunk1.arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let mut result = vec![];
+    let options = WriteOptions::new(None).with_dictionary_policy(policy);
+    let mut writer = StreamWriter::new(&mut result, options);
+    writer.start(&schema, None)?;
+    writer.write(&chunk1, None)?;
+    writer.write(&chunk2, None)?;
+    writer.finish()?;
+    Ok(result)
+}
+
+fn dictionary_chunk(values: &[&str], keys: Vec<i32>) -> Chunk<Box<dyn Array>> {
+    let values = Utf8Array::<i32>::from_slice(values).boxed();
+    let array = DictionaryArray::try_from_keys(PrimitiveArray::from_vec(keys), values).unwrap();
+    Chunk::new(vec![array.boxed()])
+}
+
+fn read_dictionaries(data: Vec<u8>) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    StreamReader::new(reader, metadata, None)
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect()
+}
+
+#[test]
+fn dictionary_policy_replace() -> Result<()> {
+    let chunks = read_dictionaries(write_dictionaries(DictionaryPolicy::Replace)?)?;
+    assert_eq!(
+        chunks,
+        vec![
+            dictionary_chunk(&["a", "b"], vec![0, 1]),
+            dictionary_chunk(&["a", "b", "c"], vec![2, 0])
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_policy_error() {
+    assert!(write_dictionaries(DictionaryPolicy::Error).is_err());
+}
+
+#[test]
+fn dictionary_policy_delta() -> Result<()> {
+    let replace = write_dictionaries(DictionaryPolicy::Replace)?;
+    let delta = write_dictionaries(DictionaryPolicy::Delta)?;
+    // only the new value ("c") is written in the second dictionary batch
+    assert!(delta.len() < replace.len());
+
+    // the delta is appended to the previous dictionary
+    assert_eq!(
+        read_dictionaries(delta)?,
+        vec![
+            dictionary_chunk(&["a", "b"], vec![0, 1]),
+            dictionary_chunk(&["a", "b", "c"], vec![2, 0])
+        ]
+    );
+    Ok(())
+}
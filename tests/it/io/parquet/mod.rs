@@ -1121,6 +1121,8 @@ fn integration_write(schema: &Schema, chunks: &[Chunk<Box<dyn Array>>]) -> Resul
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let encodings = schema
@@ -1457,6 +1459,23 @@ fn list_utf8_nullable() -> Result<()> {
     list_array_generic(true, array.into(), None)
 }
 
+#[test]
+fn list_list_utf8_nullable() -> Result<()> {
+    let s = |x: &str| Some(x.to_string());
+    let data = vec![
+        Some(vec![Some(vec![s("a")]), None, Some(vec![None, s("b")])]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(vec![]), Some(vec![None])]),
+        Some(vec![None, Some(vec![s("c"), None, s("d")])]),
+    ];
+    let inner =
+        MutableListArray::<i32, _>::new_with_field(MutableUtf8Array::<i32>::new(), "item", true);
+    let mut array = MutableListArray::<i32, _>::new_with_field(inner, "item", true);
+    array.try_extend(data).unwrap();
+    list_array_generic(true, array.into(), None)
+}
+
 #[test]
 fn list_int_nullable() -> Result<()> {
     let data = vec![
@@ -613,3 +613,136 @@ fn invalid_utf8() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+#[ignore = "requires more than 8GB of memory"]
+fn large_utf8_over_i32_offsets() -> Result<()> {
+    let value = "a".repeat(512 * 1024 * 1024);
+    let array = Utf8Array::<i64>::from_slice(vec![value.as_str(); 5]);
+    drop(value);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let chunk = Chunk::new(vec![array.boxed()]);
+    let data = super::integration_write(&schema, &[chunk])?;
+
+    // declared as LargeUtf8 => i64 offsets
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    let length = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+    assert_eq!(length, 5);
+
+    // forced into Utf8 => i32 offsets overflow
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+    let error = reader.collect::<Result<Vec<_>>>().unwrap_err();
+    match error {
+        Error::External(_, error) => assert_eq!(
+            *error.downcast::<OffsetOverflowError>().unwrap(),
+            OffsetOverflowError {
+                column: "a".to_string(),
+                data_type: DataType::Utf8,
+            }
+        ),
+        error => panic!("unexpected error {error:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn field_types_of_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let array = DictionaryArray::try_from_keys(Int32Array::from_slice([0, 1, 0]), values)?;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let data = integration_write(&schema, &[Chunk::new(vec![array.boxed()])])?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let types = infer_field_types(&metadata)?;
+    assert_eq!(types.len(), 1);
+    let (field, columns) = &types[0];
+    assert_eq!(field, &schema.fields[0]);
+    assert_eq!(
+        columns,
+        &vec![ColumnTypes {
+            path_in_schema: vec!["a".to_string()],
+            physical_type: PhysicalType::ByteArray,
+            logical_type: Some(PrimitiveLogicalType::String),
+            converted_type: Some(PrimitiveConvertedType::Utf8),
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn statistics_only() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk1 = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("b"), Some("a"), None]).boxed(),
+    ]);
+    let chunk2 = Chunk::new(vec![
+        Int32Array::from([Some(-1), Some(5)]).boxed(),
+        Utf8Array::<i32>::from([Some("z"), Some("y")]).boxed(),
+    ]);
+    let data = integration_write(&schema, &[chunk1, chunk2])?;
+
+    let statistics = read_statistics_only(&mut Cursor::new(data))?;
+    assert_eq!(statistics.len(), 2);
+
+    let (field, a) = &statistics[0];
+    assert_eq!(field, &schema.fields[0]);
+    assert_eq!(
+        a.min_value.as_ref(),
+        &Int32Array::from_slice([1, -1]) as &dyn Array
+    );
+    assert_eq!(
+        a.max_value.as_ref(),
+        &Int32Array::from_slice([3, 5]) as &dyn Array
+    );
+    assert_eq!(
+        a.null_count.as_ref(),
+        &UInt64Array::from_slice([1, 0]) as &dyn Array
+    );
+
+    let (field, b) = &statistics[1];
+    assert_eq!(field, &schema.fields[1]);
+    assert_eq!(
+        b.min_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "y"]) as &dyn Array
+    );
+    assert_eq!(
+        b.max_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["b", "z"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn row_group_filter() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunks = [[1, 2], [10, 11], [20, 21]]
+        .into_iter()
+        .map(|values| Chunk::new(vec![Int32Array::from_slice(values).boxed()]))
+        .collect::<Vec<_>>();
+    let data = integration_write(&schema, &chunks)?;
+
+    // keep the row groups that may contain values >= 10
+    let predicate = |statistics: &[(Field, statistics::Statistics)]| {
+        let max = statistics[0]
+            .1
+            .max_value
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        !max.is_valid(0) || max.value(0) >= 10
+    };
+    let reader = read_with_row_group_filter(Cursor::new(data), predicate, None)?;
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks[1..].to_vec());
+    Ok(())
+}
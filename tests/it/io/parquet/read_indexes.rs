@@ -28,6 +28,8 @@ fn pages(arrays: &[&dyn Array], encoding: Encoding) -> Result<(Vec<Page>, Vec<Pa
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let pages1 = [array11, array12, array13]
@@ -70,14 +72,24 @@ fn pages(arrays: &[&dyn Array], encoding: Encoding) -> Result<(Vec<Page>, Vec<Pa
 
 /// Tests reading pages while skipping indexes
 fn read_with_indexes(
+    pages: (Vec<Page>, Vec<Page>, Schema),
+    expected: Box<dyn Array>,
+) -> Result<()> {
+    read_with_indexes_and_options(pages, expected, Default::default())
+}
+
+fn read_with_indexes_and_options(
     (pages1, pages2, schema): (Vec<Page>, Vec<Page>, Schema),
     expected: Box<dyn Array>,
+    read_options: ReadOptions,
 ) -> Result<()> {
     let options = WriteOptions {
         write_statistics: true,
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let to_compressed = |pages: Vec<Page>| {
@@ -149,7 +161,8 @@ fn read_with_indexes(
         Some(1024 * 8 * 8),
         None,
         Some(pages),
-    );
+    )
+    .with_options(read_options);
 
     let arrays = chunks.collect::<Result<Vec<_>>>()?;
 
@@ -178,6 +191,61 @@ fn indexed_required_utf8_delta() -> Result<()> {
     )
 }
 
+fn assert_unsupported_encoding(result: Result<()>) {
+    match result {
+        Err(Error::External(_, error)) => {
+            let error = error.downcast::<UnsupportedEncodingError>().unwrap();
+            assert_eq!(error.encoding, Encoding::DeltaByteArray);
+            assert!(error.is_filtered);
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn indexed_required_utf8_delta_bytes_unsupported() -> Result<()> {
+    // index-filtered "DELTA_BYTE_ARRAY"-encoded pages are not supported
+    let array21 = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let array22 = Utf8Array::<i32>::from_slice(["d", "e", "f"]);
+    let expected = Utf8Array::<i32>::from_slice(["e"]).boxed();
+
+    assert_unsupported_encoding(read_with_indexes(
+        pages(&[&array21, &array22], Encoding::DeltaByteArray)?,
+        expected.clone(),
+    ));
+
+    // a required column cannot be null-filled
+    assert_unsupported_encoding(read_with_indexes_and_options(
+        pages(&[&array21, &array22], Encoding::DeltaByteArray)?,
+        expected,
+        ReadOptions {
+            on_unsupported_encoding: UnsupportedEncoding::SkipColumn,
+        },
+    ));
+    Ok(())
+}
+
+#[test]
+fn indexed_optional_utf8_delta_bytes_unsupported() -> Result<()> {
+    let array21 = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let array22 = Utf8Array::<i32>::from([None, Some("e"), Some("f")]);
+    let expected = Utf8Array::<i32>::from_slice(["e"]).boxed();
+
+    assert_unsupported_encoding(read_with_indexes(
+        pages(&[&array21, &array22], Encoding::DeltaByteArray)?,
+        expected,
+    ));
+
+    let expected = Utf8Array::<i32>::new_null(DataType::Utf8, 1).boxed();
+    read_with_indexes_and_options(
+        pages(&[&array21, &array22], Encoding::DeltaByteArray)?,
+        expected,
+        ReadOptions {
+            on_unsupported_encoding: UnsupportedEncoding::SkipColumn,
+        },
+    )
+}
+
 #[test]
 fn indexed_required_i32() -> Result<()> {
     let array21 = Int32Array::from_slice([1, 2, 3]);
@@ -48,6 +48,8 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let iter = vec![Chunk::try_new(vec![array.clone()])];
@@ -585,3 +587,387 @@ fn struct_v2() -> Result<()> {
         vec![Encoding::Plain, Encoding::Plain],
     )
 }
+
+#[test]
+fn append_row_groups_to_file() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk1 = Chunk::new(vec![
+        Int32Array::from([Some(1), None]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b")]).boxed(),
+    ]);
+    let chunk2 = Chunk::new(vec![
+        Int32Array::from([Some(3), Some(4), Some(5)]).boxed(),
+        Utf8Array::<i32>::from([None, Some("d"), Some("e")]).boxed(),
+    ]);
+    let data = integration_write(&schema, &[chunk1.clone()])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
+    };
+    let mut file = Cursor::new(data.clone());
+    let size = append_row_groups(
+        &mut file,
+        &schema,
+        vec![Ok(chunk2.clone())].into_iter(),
+        options,
+        vec![vec![Encoding::Plain], vec![Encoding::Plain]],
+    )?;
+    let appended = file.into_inner();
+    assert_eq!(size, appended.len() as u64);
+    // the existing bytes are not overwritten
+    assert_eq!(&appended[..data.len()], data.as_slice());
+    let data = appended;
+
+    let (read_schema, chunks) = integration_read(&data, None)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(chunks, vec![chunk1, chunk2]);
+    Ok(())
+}
+
+#[test]
+fn append_row_groups_different_schema() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let data = integration_write(&schema, &[chunk])?;
+
+    let other = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let chunk = Chunk::new(vec![Int64Array::from_slice([1, 2]).boxed()]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
+    };
+    let result = append_row_groups(
+        &mut Cursor::new(data),
+        &other,
+        vec![Ok(chunk)].into_iter(),
+        options,
+        vec![vec![Encoding::Plain]],
+    );
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn max_rows_per_page() -> Result<()> {
+    let array = Utf8Array::<i32>::from_iter((0..25).map(|x| Some(x.to_string())));
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        max_rows_per_page: Some(10),
+        max_statistics_size: None,
+    };
+
+    let pages = array_to_pages(
+        &array,
+        parquet_schema.columns()[0]
+            .descriptor
+            .primitive_type
+            .clone(),
+        &[Nested::Primitive(None, true, array.len())],
+        options,
+        Encoding::Plain,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+
+    let rows = pages
+        .iter()
+        .map(|page| match page {
+            Page::Data(page) => page.num_values(),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![10, 10, 5]);
+    Ok(())
+}
+
+#[test]
+fn max_rows_per_page_dictionary() -> Result<()> {
+    // every row whose key is 1 points to the null value
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed();
+    let keys = PrimitiveArray::from_vec((0..25).map(|x| x % 3).collect::<Vec<i32>>());
+    let array = DictionaryArray::try_from_keys(keys, values)?;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        max_rows_per_page: Some(10),
+        max_statistics_size: None,
+    };
+
+    let pages = array_to_pages(
+        &array,
+        parquet_schema.columns()[0]
+            .descriptor
+            .primitive_type
+            .clone(),
+        &[Nested::Primitive(None, true, array.len())],
+        options,
+        Encoding::RleDictionary,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+
+    // a single dictionary page followed by the data pages
+    assert!(matches!(pages[0], Page::Dict(_)));
+    let rows = pages[1..]
+        .iter()
+        .map(|page| match page {
+            Page::Data(page) => page.num_values(),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![10, 10, 5]);
+
+    // each page counts its own nulls, not the ones of the dictionary
+    let null_counts = pages[1..]
+        .iter()
+        .map(|page| match page {
+            Page::Data(page) => match page.header() {
+                p_read::DataPageHeader::V1(header) => {
+                    header.statistics.as_ref().unwrap().null_count
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(null_counts, vec![Some(3), Some(4), Some(1)]);
+
+    let chunk = Chunk::new(vec![array.boxed()]);
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::RleDictionary]],
+    )?;
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let (_, chunks) = integration_read(&writer.into_inner(), None)?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn deterministic_key_value_metadata() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
+    };
+    let key_value = |key: &str| KeyValue {
+        key: key.to_string(),
+        value: Some("value".to_string()),
+    };
+
+    let write = |key_value_metadata: Vec<KeyValue>| -> Result<Vec<u8>> {
+        let row_groups = RowGroupIterator::try_new(
+            vec![Ok(chunk.clone())].into_iter(),
+            &schema,
+            options,
+            vec![vec![Encoding::Plain]],
+        )?;
+        let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(Some(key_value_metadata))?;
+        Ok(writer.into_inner().into_inner())
+    };
+
+    let data1 = write(vec![key_value("b"), key_value("a")])?;
+    let data2 = write(vec![key_value("a"), key_value("b")])?;
+    assert_eq!(data1, data2);
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(data1))?;
+    let keys = metadata
+        .key_value_metadata()
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|x| x.key.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec!["ARROW:schema", "a", "b"]);
+    Ok(())
+}
+
+#[test]
+fn utf8_as_binary() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bb")]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Binary, true)]);
+    let data = integration_write(&schema, &[Chunk::new(vec![array.boxed()])])?;
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    let column = &metadata.schema().columns()[0].descriptor.primitive_type;
+    assert_eq!(column.logical_type, None);
+    assert_eq!(column.converted_type, None);
+
+    let (read_schema, chunks) = integration_read(&data, None)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(
+        chunks,
+        vec![Chunk::new(vec![BinaryArray::<i32>::from([
+            Some(b"a".as_ref()),
+            None,
+            Some(b"bb".as_ref())
+        ])
+        .boxed()])]
+    );
+    Ok(())
+}
+
+fn write_utf8_statistics(
+    array: Utf8Array<i32>,
+    max_statistics_size: Option<usize>,
+) -> Result<Statistics> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size,
+    };
+
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(Chunk::new(vec![array.boxed()]))].into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let mut statistics = p_read::read_statistics_only(&mut Cursor::new(data))?;
+    Ok(statistics.pop().unwrap().1)
+}
+
+#[test]
+fn max_statistics_size() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("aébc"), None, Some("zzzz")]);
+
+    let statistics = write_utf8_statistics(array.clone(), None)?;
+    assert_eq!(
+        statistics.min_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["aébc"]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.max_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["zzzz"]) as &dyn Array
+    );
+
+    // "é" is 2 bytes long and does not fit in the first 2 bytes
+    let statistics = write_utf8_statistics(array.clone(), Some(2))?;
+    assert_eq!(
+        statistics.min_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.max_value.as_ref(),
+        &Utf8Array::<i32>::from_slice(["z{"]) as &dyn Array
+    );
+
+    let statistics = write_utf8_statistics(array, Some(0))?;
+    assert_eq!(
+        statistics.min_value.as_ref(),
+        &Utf8Array::<i32>::from([None::<&str>]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.max_value.as_ref(),
+        &Utf8Array::<i32>::from([None::<&str>]) as &dyn Array
+    );
+    assert_eq!(
+        statistics.null_count.as_ref(),
+        &UInt64Array::from_slice([1]) as &dyn Array
+    );
+    Ok(())
+}
+
+fn delta_byte_array_round_trip(array: Utf8Array<i32>, is_nullable: bool) -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, is_nullable)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
+    };
+    let chunk = Chunk::new(vec![array.boxed()]);
+
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::DeltaByteArray]],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let (read_schema, chunks) = integration_read(&data, None)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn delta_byte_array_optional() -> Result<()> {
+    delta_byte_array_round_trip(
+        Utf8Array::<i32>::from([
+            Some("2023-01-01T00:00:00"),
+            None,
+            Some("2023-01-01T00:00:01"),
+            Some("2023-01-01T00:01:00"),
+            None,
+            Some("2023-01-02"),
+            Some(""),
+            Some("2023-01-02T00:00:00"),
+            None,
+        ]),
+        true,
+    )
+}
+
+#[test]
+fn delta_byte_array_required() -> Result<()> {
+    delta_byte_array_round_trip(
+        Utf8Array::<i32>::from_slice(["id-0001", "id-0002", "id-0010", "id-0100", "jd", "jd"]),
+        false,
+    )
+}
@@ -32,6 +32,8 @@ async fn test_parquet_async_roundtrip() {
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        max_rows_per_page: None,
+        max_statistics_size: None,
     };
 
     let mut buffer = Cursor::new(Vec::new());